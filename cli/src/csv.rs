@@ -0,0 +1,231 @@
+use crate::StyleArg;
+use clap::Args;
+use nhs_number::csv::{Column, CsvProcessor, RowReport};
+use nhs_number::parse_options::ParseOptions;
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+
+/// Arguments for the command `csv`.
+#[derive(Debug, Args)]
+pub struct CsvArgs {
+    /// CSV file to read; without a file, read stdin.
+    #[arg(value_name = "PATH")]
+    path: Option<PathBuf>,
+
+    /// Header name of the NHS Number column.
+    #[arg(
+        long,
+        required_unless_present = "column_index",
+        conflicts_with = "column_index"
+    )]
+    column: Option<String>,
+
+    /// Index of the NHS Number column, starting at zero.
+    #[arg(long, value_name = "INDEX")]
+    column_index: Option<usize>,
+
+    /// Write the cleaned CSV to a file; without a file, write stdout.
+    #[arg(short, long, value_name = "PATH")]
+    output: Option<PathBuf>,
+
+    /// Write a CSV report of invalid rows to a file; without a file, write
+    /// the report to stderr.
+    #[arg(long, value_name = "PATH")]
+    report: Option<PathBuf>,
+
+    /// Display style of each NHS Number in the cleaned CSV.
+    #[arg(long, value_enum, default_value_t = StyleArg::Compact)]
+    style: StyleArg,
+
+    /// Accept any separators, Unicode digits, and prefixes such as "NHS No:".
+    #[arg(long)]
+    lenient: bool,
+
+    /// Also reject repeated digits, and ranges that are not issuable; without
+    /// this, only the format and the check digit are checked.
+    #[arg(long)]
+    full: bool,
+
+    /// Write each invalid row unchanged to the cleaned CSV.
+    #[arg(long)]
+    keep_invalid: bool,
+
+    /// Field delimiter, which is an ASCII character.
+    #[arg(long, default_value_t = ',', value_parser = parse_delimiter)]
+    delimiter: char,
+}
+
+/// Parse a delimiter, which must be one ASCII character.
+fn parse_delimiter(s: &str) -> Result<char, String> {
+    match s {
+        "\\t" => Ok('\t'),
+        _ => {
+            let mut chars = s.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) if c.is_ascii() => Ok(c),
+                _ => Err("expected one ASCII character".into()),
+            }
+        }
+    }
+}
+
+/// Run the command `csv`, and return whether every row is valid.
+pub fn run(args: CsvArgs, out: &mut impl Write, err: &mut impl Write) -> io::Result<bool> {
+    let column = match (args.column, args.column_index) {
+        (Some(name), _) => Column::Name(name),
+        (None, Some(index)) => Column::Index(index),
+        (None, None) => unreachable!("clap requires a column"),
+    };
+    let processor = CsvProcessor::new(column)
+        .options(if args.lenient {
+            ParseOptions::lenient()
+        } else {
+            ParseOptions::strict()
+        })
+        .style(args.style.into())
+        .full(args.full)
+        .keep_invalid(args.keep_invalid)
+        .delimiter(args.delimiter as u8);
+    let reader: Box<dyn Read> = match &args.path {
+        Some(path) => Box::new(open(path)?),
+        None => Box::new(io::stdin().lock()),
+    };
+    let writer: Box<dyn Write + '_> = match &args.output {
+        Some(path) => Box::new(create(path)?),
+        None => Box::new(&mut *out),
+    };
+    let mut report = match &args.report {
+        Some(path) => {
+            let mut report = ::csv::Writer::from_writer(create(path)?);
+            report.write_record(["line", "input", "kind", "error"])?;
+            Some(report)
+        }
+        None => None,
+    };
+    let mut result = Ok(());
+    let summary = processor
+        .process(reader, writer, |row: RowReport| {
+            if result.is_ok() {
+                result = match &mut report {
+                    Some(report) => report
+                        .write_record([
+                            row.line.to_string(),
+                            row.input,
+                            row.error.kind().to_string(),
+                            row.error.to_string(),
+                        ])
+                        .map_err(io::Error::from),
+                    None => writeln!(
+                        err,
+                        "line {}: {:?}: {}: {}",
+                        row.line,
+                        row.input,
+                        row.error.kind(),
+                        row.error
+                    ),
+                }
+            }
+        })
+        .map_err(io::Error::other)?;
+    result?;
+    if let Some(mut report) = report {
+        report.flush()?;
+    }
+    writeln!(
+        err,
+        "{} rows: {} valid, {} invalid",
+        summary.rows, summary.valid, summary.invalid
+    )?;
+    Ok(summary.invalid == 0)
+}
+
+/// Open a file to read, with the path in any error.
+fn open(path: &Path) -> io::Result<File> {
+    File::open(path)
+        .map_err(|error| io::Error::new(error.kind(), format!("{}: {}", path.display(), error)))
+}
+
+/// Create a file to write, with the path in any error.
+fn create(path: &Path) -> io::Result<File> {
+    File::create(path)
+        .map_err(|error| io::Error::new(error.kind(), format!("{}: {}", path.display(), error)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::run_args;
+
+    /// Write the contents to a file in the temporary directory.
+    fn temp_file(name: &str, contents: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(name);
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_csv() {
+        let path = temp_file(
+            "nhs-number-cli-test-csv.csv",
+            "id,nhs\n1,999 345 6780\n2,999 345 6781\n",
+        );
+        let (valid, out, err) = run_args(&["csv", "--column", "nhs", path.to_str().unwrap()]);
+        std::fs::remove_file(path).unwrap();
+        assert!(!valid);
+        assert_eq!(out, "id,nhs\n1,9993456780\n");
+        assert_eq!(
+            err,
+            "line 3: \"999 345 6781\": check digit: invalid check digit: expected 0, found 1\n\
+             2 rows: 1 valid, 1 invalid\n"
+        );
+    }
+
+    #[test]
+    fn test_csv_with_output_and_report() {
+        let path = temp_file(
+            "nhs-number-cli-test-csv-input.csv",
+            "nhs;id\n9434765919;1\nNHS 999 345 6780;2\n",
+        );
+        let output = std::env::temp_dir().join("nhs-number-cli-test-csv-output.csv");
+        let report = std::env::temp_dir().join("nhs-number-cli-test-csv-report.csv");
+        let (valid, out, err) = run_args(&[
+            "csv",
+            "--column-index",
+            "0",
+            "--delimiter",
+            ";",
+            "--lenient",
+            "--full",
+            "--style",
+            "spaced",
+            "--output",
+            output.to_str().unwrap(),
+            "--report",
+            report.to_str().unwrap(),
+            path.to_str().unwrap(),
+        ]);
+        let output_contents = std::fs::read_to_string(&output).unwrap();
+        let report_contents = std::fs::read_to_string(&report).unwrap();
+        for path in [path, output, report] {
+            std::fs::remove_file(path).unwrap();
+        }
+        assert!(!valid);
+        assert_eq!(out, "");
+        assert_eq!(err, "2 rows: 1 valid, 1 invalid\n");
+        assert_eq!(output_contents, "nhs;id\n999 345 6780;2\n");
+        assert_eq!(
+            report_contents,
+            "line,input,kind,error\n\
+             2,9434765919,not issuable,out of range: Unallocated numbers are not issuable\n"
+        );
+    }
+
+    #[test]
+    fn test_parse_delimiter() {
+        assert_eq!(parse_delimiter(","), Ok(','));
+        assert_eq!(parse_delimiter("\\t"), Ok('\t'));
+        assert!(parse_delimiter(";;").is_err());
+        assert!(parse_delimiter("£").is_err());
+    }
+}
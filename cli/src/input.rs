@@ -0,0 +1,123 @@
+use clap::Args;
+use nhs_number::parse_options::ParseOptions;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::PathBuf;
+
+/// Lines of input, which are each an iterator item, or an input/output error.
+pub type Lines = Box<dyn Iterator<Item = io::Result<String>>>;
+
+/// Input options, which are shared by the commands that read NHS Numbers.
+#[derive(Debug, Default, Args)]
+pub struct Input {
+    /// NHS Numbers to read; without any numbers or files, read stdin.
+    pub numbers: Vec<String>,
+
+    /// Read NHS Numbers from a file, one per line; repeat for more files.
+    #[arg(short, long = "file", value_name = "PATH")]
+    pub files: Vec<PathBuf>,
+
+    /// Accept any separators, Unicode digits, and prefixes such as "NHS No:".
+    #[arg(long)]
+    pub lenient: bool,
+}
+
+impl Input {
+    /// Get the parse options, which are strict unless the input is lenient.
+    pub fn options(&self) -> ParseOptions<'static> {
+        if self.lenient {
+            ParseOptions::lenient()
+        } else {
+            ParseOptions::strict()
+        }
+    }
+
+    /// Get the lines of input, from the arguments, then from the files, or
+    /// else from stdin. Blank lines are skipped.
+    ///
+    /// Every file is opened before any line is read, so a missing file is an
+    /// error before any output.
+    pub fn lines(&self) -> io::Result<Lines> {
+        Ok(skip_blank(self.lines_with_blanks()?))
+    }
+
+    /// Get the lines of input, like [Input::lines], but without skipping
+    /// blank lines, such as for output that must align with each input line.
+    pub fn lines_with_blanks(&self) -> io::Result<Lines> {
+        if self.numbers.is_empty() && self.files.is_empty() {
+            return Ok(Box::new(io::stdin().lines()));
+        }
+        let mut lines: Lines = Box::new(self.numbers.clone().into_iter().map(Ok));
+        for path in &self.files {
+            let file = File::open(path).map_err(|error| {
+                io::Error::new(error.kind(), format!("{}: {}", path.display(), error))
+            })?;
+            lines = Box::new(lines.chain(BufReader::new(file).lines()));
+        }
+        Ok(lines)
+    }
+}
+
+/// Skip lines that are empty or only whitespace.
+fn skip_blank(lines: Lines) -> Lines {
+    Box::new(lines.filter(|line| match line {
+        Ok(line) => !line.trim().is_empty(),
+        Err(_) => true,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    #[test]
+    fn test_lines_from_numbers() {
+        let input = Input {
+            numbers: vec!["999 345 6780".into(), " ".into(), "9993456780".into()],
+            ..Input::default()
+        };
+        let lines: Vec<String> = input.lines().unwrap().map(Result::unwrap).collect();
+        assert_eq!(lines, ["999 345 6780", "9993456780"]);
+    }
+
+    #[test]
+    fn test_lines_from_numbers_then_files() {
+        let path = std::env::temp_dir().join("nhs-number-cli-test-lines.txt");
+        let mut file = File::create(&path).unwrap();
+        writeln!(file, "999 345 6780\n\n4010232137").unwrap();
+        let input = Input {
+            numbers: vec!["9434765919".into()],
+            files: vec![path.clone()],
+            ..Input::default()
+        };
+        let lines: Vec<String> = input.lines().unwrap().map(Result::unwrap).collect();
+        std::fs::remove_file(path).unwrap();
+        assert_eq!(lines, ["9434765919", "999 345 6780", "4010232137"]);
+    }
+
+    #[test]
+    fn test_lines_with_missing_file() {
+        let input = Input {
+            files: vec![PathBuf::from("/nonexistent/nhs-numbers.txt")],
+            ..Input::default()
+        };
+        let error = input.lines().err().unwrap();
+        assert!(
+            error
+                .to_string()
+                .starts_with("/nonexistent/nhs-numbers.txt: ")
+        );
+    }
+
+    #[test]
+    fn test_options() {
+        let strict = Input::default();
+        assert!(strict.options().parse("NHS No: 999 345 6780").is_err());
+        let lenient = Input {
+            lenient: true,
+            ..Input::default()
+        };
+        assert!(lenient.options().parse("NHS No: 999 345 6780").is_ok());
+    }
+}
@@ -0,0 +1,374 @@
+//! Command-line tool to validate, format, generate, classify, and pseudonymise
+//! NHS Numbers, to clean CSV files, and to redact text.
+//!
+//! Each command that reads NHS Numbers reads them from its arguments, or from
+//! files via `--file`, or else from stdin, one per line. The commands `csv`
+//! and `redact` read files, or else stdin.
+//!
+//! Exit codes:
+//!
+//! * 0 means every input is valid.
+//!
+//! * 1 means at least one input is invalid.
+//!
+//! * 2 means a usage error, or an input/output error.
+
+use clap::{Parser, Subcommand, ValueEnum};
+use nhs_number::{NHSNumber, parse_options::ParseOptions, style::Style};
+use serde_json::json;
+use std::io::{self, Write};
+use std::process::ExitCode;
+
+mod csv;
+mod input;
+use input::Input;
+mod pseudonymise;
+mod redact;
+
+/// Validate, format, generate, classify, and pseudonymise NHS Numbers, clean
+/// CSV files, and redact text.
+#[derive(Debug, Parser)]
+#[command(name = "nhs-number", version, about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Validate each NHS Number, including its check digit.
+    Validate {
+        #[command(flatten)]
+        input: Input,
+
+        /// Also reject repeated digits, and ranges that are not issuable.
+        #[arg(long)]
+        full: bool,
+
+        /// Print JSON Lines, which is one JSON object per input.
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Format each NHS Number in a display style.
+    Format {
+        #[command(flatten)]
+        input: Input,
+
+        /// Display style.
+        #[arg(long, value_enum, default_value_t)]
+        style: StyleArg,
+    },
+
+    /// Generate random NHS Numbers in the test range 999 000 0000 to 999 999 9999.
+    Generate {
+        /// Count of NHS Numbers.
+        #[arg(short, long, default_value_t = 1)]
+        count: usize,
+
+        /// Seed, which generates the same NHS Numbers each time.
+        #[arg(short, long)]
+        seed: Option<u64>,
+
+        /// Display style.
+        #[arg(long, value_enum, default_value_t)]
+        style: StyleArg,
+    },
+
+    /// Classify each NHS Number into its issuing range.
+    Classify {
+        #[command(flatten)]
+        input: Input,
+
+        /// Print JSON Lines, which is one JSON object per input.
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Validate a NHS Number column in a CSV file, report invalid rows, and
+    /// write a cleaned CSV file.
+    Csv(csv::CsvArgs),
+
+    /// Pseudonymise each NHS Number with a secret key, via HMAC-SHA256.
+    Pseudonymise(pseudonymise::PseudonymiseArgs),
+
+    /// Redact NHS Numbers from text, such as logs, one line at a time.
+    Redact(redact::RedactArgs),
+}
+
+/// Display style argument, which maps to the library display style.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum StyleArg {
+    /// Spaces between the digit groups e.g. "999 345 6780".
+    #[default]
+    Spaced,
+
+    /// No separators e.g. "9993456780".
+    Compact,
+
+    /// Hyphens between the digit groups e.g. "999-345-6780".
+    Hyphenated,
+}
+
+impl From<StyleArg> for Style {
+    fn from(style: StyleArg) -> Self {
+        match style {
+            StyleArg::Spaced => Style::Spaced,
+            StyleArg::Compact => Style::Compact,
+            StyleArg::Hyphenated => Style::Hyphenated,
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match run(cli.command, &mut io::stdout().lock(), &mut io::stderr()) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::from(1),
+        Err(error) => {
+            eprintln!("nhs-number: {}", error);
+            ExitCode::from(2)
+        }
+    }
+}
+
+/// Run the command, and return whether every input is valid.
+fn run(command: Command, out: &mut impl Write, err: &mut impl Write) -> io::Result<bool> {
+    let mut all_valid = true;
+    match command {
+        Command::Validate { input, full, json } => {
+            let options = input.options();
+            for line in input.lines()? {
+                let line = line?;
+                let result = check(&line, &options, full);
+                all_valid &= result.is_ok();
+                match (result, json) {
+                    (Ok(nhs_number), true) => writeln!(
+                        out,
+                        "{}",
+                        json!({
+                            "input": line,
+                            "valid": true,
+                            "nhs_number": format!("{:#}", nhs_number),
+                        })
+                    )?,
+                    (Err(error), true) => writeln!(
+                        out,
+                        "{}",
+                        json!({ "input": line, "valid": false, "error": error })
+                    )?,
+                    (Ok(_), false) => writeln!(out, "{}: valid", line)?,
+                    (Err(error), false) => writeln!(out, "{}: invalid: {}", line, error)?,
+                }
+            }
+        }
+        Command::Format { input, style } => {
+            let options = input.options();
+            for line in input.lines()? {
+                let line = line?;
+                match check(&line, &options, false) {
+                    Ok(nhs_number) => writeln!(out, "{}", nhs_number.display_with(style.into()))?,
+                    Err(error) => {
+                        all_valid = false;
+                        writeln!(err, "{}: invalid: {}", line, error)?;
+                    }
+                }
+            }
+        }
+        Command::Generate { count, seed, style } => {
+            let samples: Box<dyn Iterator<Item = NHSNumber>> = match seed {
+                Some(seed) => Box::new(nhs_number::testable_random_samples(seed)),
+                None => Box::new(std::iter::repeat_with(NHSNumber::testable_random_sample)),
+            };
+            for nhs_number in samples.take(count) {
+                writeln!(out, "{}", nhs_number.display_with(style.into()))?;
+            }
+        }
+        Command::Classify { input, json } => {
+            let options = input.options();
+            for line in input.lines()? {
+                let line = line?;
+                let result = check(&line, &options, false);
+                all_valid &= result.is_ok();
+                match (result, json) {
+                    (Ok(nhs_number), true) => writeln!(
+                        out,
+                        "{}",
+                        json!({
+                            "input": line,
+                            "valid": true,
+                            "nhs_number": format!("{:#}", nhs_number),
+                            "range": nhs_number.range().to_string(),
+                            "issued": nhs_number.range().is_issued(),
+                        })
+                    )?,
+                    (Err(error), true) => writeln!(
+                        out,
+                        "{}",
+                        json!({ "input": line, "valid": false, "error": error })
+                    )?,
+                    (Ok(nhs_number), false) => writeln!(out, "{}: {}", line, nhs_number.range())?,
+                    (Err(error), false) => writeln!(out, "{}: invalid: {}", line, error)?,
+                }
+            }
+        }
+        Command::Csv(args) => all_valid = csv::run(args, out, err)?,
+        Command::Pseudonymise(args) => all_valid = pseudonymise::run(args, out, err)?,
+        Command::Redact(args) => redact::run(args, out)?,
+    }
+    Ok(all_valid)
+}
+
+/// Check the input, and return the NHS Number, or the reason it is invalid.
+///
+/// The check parses the input, including its check digit, and if `full` is
+/// true, then also does the full validation.
+fn check(input: &str, options: &ParseOptions, full: bool) -> Result<NHSNumber, String> {
+    let nhs_number = options.parse(input).map_err(|error| error.to_string())?;
+    if full {
+        nhs_number.validate().map_err(|error| error.to_string())?;
+    }
+    Ok(nhs_number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    /// Run the command line, and return whether every input is valid, the
+    /// output, and the error output.
+    pub fn run_args(args: &[&str]) -> (bool, String, String) {
+        let cli =
+            Cli::try_parse_from(std::iter::once("nhs-number").chain(args.iter().copied())).unwrap();
+        let mut out = Vec::new();
+        let mut err = Vec::new();
+        let valid = run(cli.command, &mut out, &mut err).unwrap();
+        (
+            valid,
+            String::from_utf8(out).unwrap(),
+            String::from_utf8(err).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_cli() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn test_validate() {
+        let (valid, out, _) = run_args(&["validate", "999 345 6780", "9993456780"]);
+        assert!(valid);
+        assert_eq!(out, "999 345 6780: valid\n9993456780: valid\n");
+    }
+
+    #[test]
+    fn test_validate_with_invalid() {
+        let (valid, out, _) = run_args(&["validate", "999 345 6780", "999 345 6781"]);
+        assert!(!valid);
+        assert_eq!(
+            out,
+            "999 345 6780: valid\n999 345 6781: invalid: invalid check digit: expected 0, found 1\n"
+        );
+    }
+
+    #[test]
+    fn test_validate_full() {
+        let (valid, _, _) = run_args(&["validate", "9434765919"]);
+        assert!(valid);
+        let (valid, out, _) = run_args(&["validate", "--full", "9434765919"]);
+        assert!(!valid);
+        assert_eq!(
+            out,
+            "9434765919: invalid: out of range: Unallocated numbers are not issuable\n"
+        );
+    }
+
+    #[test]
+    fn test_validate_lenient() {
+        let (valid, _, _) = run_args(&["validate", "NHS No: 999-345-6780"]);
+        assert!(!valid);
+        let (valid, _, _) = run_args(&["validate", "--lenient", "NHS No: 999-345-6780"]);
+        assert!(valid);
+    }
+
+    #[test]
+    fn test_validate_json() {
+        let (_, out, _) = run_args(&["validate", "--json", "999 345 6780", "123"]);
+        let lines: Vec<serde_json::Value> = out
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(
+            lines[0],
+            json!({"input": "999 345 6780", "valid": true, "nhs_number": "9993456780"})
+        );
+        assert_eq!(
+            lines[1],
+            json!({
+                "input": "123",
+                "valid": false,
+                "error": "invalid length: expected 10 characters, found 3"
+            })
+        );
+    }
+
+    #[test]
+    fn test_format() {
+        let (valid, out, err) = run_args(&["format", "--style", "hyphenated", "9993456780", "x"]);
+        assert!(!valid);
+        assert_eq!(out, "999-345-6780\n");
+        assert_eq!(
+            err,
+            "x: invalid: invalid length: expected 10 characters, found 1\n"
+        );
+    }
+
+    #[test]
+    fn test_generate_with_seed() {
+        let (valid, out, _) = run_args(&["generate", "--count", "5", "--seed", "42"]);
+        assert!(valid);
+        let expect: Vec<String> = nhs_number::testable_random_samples(42)
+            .take(5)
+            .map(|nhs_number| nhs_number.to_string())
+            .collect();
+        assert_eq!(out.lines().collect::<Vec<_>>(), expect);
+    }
+
+    #[test]
+    fn test_generate_without_seed() {
+        let (_, out, _) = run_args(&["generate", "-c", "3", "--style", "compact"]);
+        assert_eq!(out.lines().count(), 3);
+        for line in out.lines() {
+            let nhs_number: NHSNumber = line.parse().unwrap();
+            assert!(nhs_number.validate().is_ok());
+        }
+    }
+
+    #[test]
+    fn test_classify() {
+        let (valid, out, _) = run_args(&["classify", "999 345 6780", "4010232137"]);
+        assert!(valid);
+        assert_eq!(
+            out,
+            "999 345 6780: Test\n4010232137: England, Wales, Isle of Man\n"
+        );
+    }
+
+    #[test]
+    fn test_classify_json() {
+        let (_, out, _) = run_args(&["classify", "--json", "4010232137"]);
+        let line: serde_json::Value = serde_json::from_str(out.trim()).unwrap();
+        assert_eq!(
+            line,
+            json!({
+                "input": "4010232137",
+                "valid": true,
+                "nhs_number": "4010232137",
+                "range": "England, Wales, Isle of Man",
+                "issued": true,
+            })
+        );
+    }
+}
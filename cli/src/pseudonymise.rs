@@ -0,0 +1,186 @@
+use crate::Input;
+use clap::{Args, ValueEnum};
+use nhs_number::pseudonym::{Key, pseudonymise};
+use std::io::{self, Write};
+use std::path::PathBuf;
+
+/// Arguments for the command `pseudonymise`.
+#[derive(Debug, Args)]
+pub struct PseudonymiseArgs {
+    #[command(flatten)]
+    input: Input,
+
+    /// File of the secret key, which must be at least 32 bytes; a trailing
+    /// newline is ignored.
+    #[arg(long, value_name = "PATH")]
+    key_file: PathBuf,
+
+    /// Key id, such as "2026-q4", which is printed to stderr for the record.
+    #[arg(long, value_name = "ID")]
+    key_id: String,
+
+    /// Encoding of each pseudonym.
+    #[arg(long, value_enum, default_value_t)]
+    encoding: Encoding,
+}
+
+/// Encoding of each pseudonym.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Encoding {
+    /// Lowercase hex, which is 64 characters.
+    #[default]
+    Hex,
+
+    /// RFC 4648 base32, uppercase without padding, which is 52 characters.
+    Base32,
+}
+
+/// Placeholder that is printed instead of a pseudonym for an invalid input, so
+/// that each output line stays aligned with its input line.
+const INVALID_PLACEHOLDER: &str = "invalid";
+
+/// Run the command `pseudonymise`, and return whether every input is valid.
+///
+/// Each input line, including a blank line, prints one output line, which is
+/// either the pseudonym, or the placeholder "invalid". Each invalid input also
+/// prints its line number and the reason to stderr, but never the input itself.
+pub fn run(args: PseudonymiseArgs, out: &mut impl Write, err: &mut impl Write) -> io::Result<bool> {
+    let mut secret = std::fs::read(&args.key_file).map_err(|error| {
+        io::Error::new(
+            error.kind(),
+            format!("{}: {}", args.key_file.display(), error),
+        )
+    })?;
+    while secret
+        .last()
+        .is_some_and(|byte| *byte == b'\n' || *byte == b'\r')
+    {
+        secret.pop();
+    }
+    let key = Key::new(args.key_id, secret).map_err(|error| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{}: {}", args.key_file.display(), error),
+        )
+    })?;
+    writeln!(err, "key id: {}", key.id())?;
+    let options = args.input.options();
+    let mut all_valid = true;
+    for (index, line) in args.input.lines_with_blanks()?.enumerate() {
+        let line = line?;
+        match options.parse(&line) {
+            Ok(nhs_number) => {
+                let pseudonym = pseudonymise(&nhs_number, &key);
+                match args.encoding {
+                    Encoding::Hex => writeln!(out, "{}", pseudonym.to_hex())?,
+                    Encoding::Base32 => writeln!(out, "{}", pseudonym.to_base32())?,
+                }
+            }
+            Err(error) => {
+                all_valid = false;
+                writeln!(out, "{}", INVALID_PLACEHOLDER)?;
+                writeln!(err, "line {}: invalid: {}", index + 1, error)?;
+            }
+        }
+    }
+    Ok(all_valid)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::run_args;
+    use crate::{Cli, run};
+    use clap::Parser;
+
+    #[test]
+    fn test_pseudonymise_aligns_output_with_input() {
+        let key_file = std::env::temp_dir().join("nhs-number-cli-test-align.key");
+        std::fs::write(&key_file, "0123456789abcdef0123456789abcdef").unwrap();
+        let input_file = std::env::temp_dir().join("nhs-number-cli-test-align.txt");
+        std::fs::write(&input_file, "9993456780\n\n999 345 6781\n9434765919\n").unwrap();
+        let (valid, out, err) = run_args(&[
+            "pseudonymise",
+            "--key-file",
+            key_file.to_str().unwrap(),
+            "--key-id",
+            "k1",
+            "--file",
+            input_file.to_str().unwrap(),
+        ]);
+        std::fs::remove_file(&key_file).unwrap();
+        std::fs::remove_file(&input_file).unwrap();
+        assert!(!valid);
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[1], "invalid");
+        assert_eq!(lines[2], "invalid");
+        assert!(!err.contains("6781"));
+        assert!(err.contains("line 2: invalid"));
+        assert!(err.contains("line 3: invalid"));
+    }
+
+    #[test]
+    fn test_pseudonymise_with_short_key() {
+        let key_file = std::env::temp_dir().join("nhs-number-cli-test-short.key");
+        std::fs::write(&key_file, "secret\n").unwrap();
+        let cli = Cli::try_parse_from([
+            "nhs-number",
+            "pseudonymise",
+            "--key-file",
+            key_file.to_str().unwrap(),
+            "--key-id",
+            "k1",
+            "999 345 6780",
+        ])
+        .unwrap();
+        let result = run(cli.command, &mut Vec::new(), &mut Vec::new());
+        std::fs::remove_file(&key_file).unwrap();
+        let error = result.unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+        assert!(
+            error
+                .to_string()
+                .ends_with("key too short: expected at least 32 bytes, found 6")
+        );
+    }
+
+    #[test]
+    fn test_pseudonymise() {
+        let key_file = std::env::temp_dir().join("nhs-number-cli-test-pseudonymise.key");
+        std::fs::write(&key_file, "0123456789abcdef0123456789abcdef\n").unwrap();
+        let key_file = key_file.to_str().unwrap();
+        let (valid, out, err) = run_args(&[
+            "pseudonymise",
+            "--key-file",
+            key_file,
+            "--key-id",
+            "k1",
+            "x",
+            "999 345 6780",
+        ]);
+        let (_, base32, _) = run_args(&[
+            "pseudonymise",
+            "--key-file",
+            key_file,
+            "--key-id",
+            "k1",
+            "--encoding",
+            "base32",
+            "9434765919",
+        ]);
+        std::fs::remove_file(key_file).unwrap();
+        assert!(!valid);
+        assert_eq!(
+            out,
+            "invalid\nd44df8b50b4b534c202f0db5c533b03a4e0b6913421bd0cdcc02bf272b9d7197\n"
+        );
+        assert_eq!(
+            err,
+            "key id: k1\nline 1: invalid: invalid length: expected 10 characters, found 1\n"
+        );
+        assert_eq!(
+            base32,
+            "6IYJBI3EKG72OQCXVFY3CFWNVVNJ2XDTO7SYGSPK6M3LDGRXFJOQ\n"
+        );
+    }
+}
@@ -0,0 +1,105 @@
+use clap::Args;
+use nhs_number::mask::MaskPolicy;
+use nhs_number::redact::{Redactor, Replacement};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::PathBuf;
+
+/// Arguments for the command `redact`.
+#[derive(Debug, Args)]
+pub struct RedactArgs {
+    /// Files to read; without any files, read stdin.
+    #[arg(value_name = "PATH")]
+    files: Vec<PathBuf>,
+
+    /// Replace each NHS Number with this token, instead of a mask.
+    #[arg(long, conflicts_with = "visible")]
+    token: Option<String>,
+
+    /// Count of trailing digits to leave visible in each mask.
+    #[arg(long, default_value_t = 0, value_name = "COUNT")]
+    visible: usize,
+
+    /// Parse each line as JSON, and redact its strings, keys, and ten-digit
+    /// integers; a line that is not JSON is redacted as text.
+    #[arg(long)]
+    json: bool,
+}
+
+/// Run the command `redact`.
+pub fn run(args: RedactArgs, out: &mut impl Write) -> io::Result<()> {
+    let replacement = match &args.token {
+        Some(token) => Replacement::Token(token),
+        None => Replacement::Mask(MaskPolicy::trailing(args.visible)),
+    };
+    let redactor = Redactor::new(replacement);
+    let mut readers: Vec<Box<dyn BufRead>> = Vec::new();
+    for path in &args.files {
+        let file = File::open(path).map_err(|error| {
+            io::Error::new(error.kind(), format!("{}: {}", path.display(), error))
+        })?;
+        readers.push(Box::new(BufReader::new(file)));
+    }
+    if readers.is_empty() {
+        readers.push(Box::new(io::stdin().lock()));
+    }
+    for reader in readers {
+        for line in reader.lines() {
+            let line = line?;
+            if args.json
+                && let Ok(mut value) = serde_json::from_str::<serde_json::Value>(&line)
+            {
+                redactor.redact_json(&mut value);
+                writeln!(out, "{}", value)?;
+            } else {
+                writeln!(out, "{}", redactor.redact(&line))?;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::run_args;
+
+    /// Write the contents to a file in the temporary directory.
+    fn temp_file(name: &str, contents: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(name);
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_redact() {
+        let path = temp_file(
+            "nhs-number-cli-test-redact.log",
+            "lookup 999 345 6780 failed\nok\n",
+        );
+        let (valid, out, _) = run_args(&["redact", path.to_str().unwrap()]);
+        let (_, visible, _) = run_args(&["redact", "--visible", "4", path.to_str().unwrap()]);
+        let (_, token, _) = run_args(&["redact", "--token", "[NHS]", path.to_str().unwrap()]);
+        let huge = usize::MAX.to_string();
+        let (_, all_visible, _) = run_args(&["redact", "--visible", &huge, path.to_str().unwrap()]);
+        std::fs::remove_file(path).unwrap();
+        assert!(valid);
+        assert_eq!(out, "lookup *** *** **** failed\nok\n");
+        assert_eq!(visible, "lookup *** *** 6780 failed\nok\n");
+        assert_eq!(token, "lookup [NHS] failed\nok\n");
+        assert_eq!(all_visible, "lookup 999 345 6780 failed\nok\n");
+    }
+
+    #[test]
+    fn test_redact_json() {
+        let path = temp_file(
+            "nhs-number-cli-test-redact.jsonl",
+            "{\"nhs\":9993456780,\"msg\":\"for 999-345-6780\"}\nnot json 9993456780\n",
+        );
+        let (_, out, _) = run_args(&["redact", "--json", path.to_str().unwrap()]);
+        std::fs::remove_file(path).unwrap();
+        assert_eq!(
+            out,
+            "{\"msg\":\"for *** *** ****\",\"nhs\":\"*** *** ****\"}\nnot json *** *** ****\n"
+        );
+    }
+}
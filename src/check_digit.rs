@@ -0,0 +1,108 @@
+/// NHS Number check digit, which is the result of the checksum algorithm.
+///
+/// The checksum algorithm yields a number in the range 1–11:
+///
+/// * A checksum in the range 1–9 is the check digit itself.
+///
+/// * A checksum of 11 is represented by the check digit 0.
+///
+/// * A checksum of 10 is invalid, which means the first nine digits can never
+///   be part of a valid NHS Number.
+///
+/// Example:
+///
+/// ```rust
+/// use nhs_number::{CheckDigit, calculate_check_digit};
+/// let digits = [9, 9, 9, 3, 4, 5, 6, 7, 8, 0];
+/// assert_eq!(calculate_check_digit(digits), CheckDigit::Digit(0));
+/// ```
+///
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum CheckDigit {
+    /// The checksum is valid, and yields this check digit in the range 0–9.
+    Digit(i8),
+
+    /// The checksum is 10, so no check digit can make the number valid.
+    Invalid,
+}
+
+impl CheckDigit {
+    /// Convert a checksum in the range 1–11 into a check digit.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use nhs_number::CheckDigit;
+    /// assert_eq!(CheckDigit::from_checksum(9), CheckDigit::Digit(9));
+    /// assert_eq!(CheckDigit::from_checksum(10), CheckDigit::Invalid);
+    /// assert_eq!(CheckDigit::from_checksum(11), CheckDigit::Digit(0));
+    /// ```
+    ///
+    pub const fn from_checksum(checksum: usize) -> Self {
+        match checksum {
+            11 => CheckDigit::Digit(0),
+            10 => CheckDigit::Invalid,
+            _ => CheckDigit::Digit(checksum as i8),
+        }
+    }
+
+    /// Get the check digit, or `None` if the checksum is invalid.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use nhs_number::CheckDigit;
+    /// assert_eq!(CheckDigit::Digit(7).digit(), Some(7));
+    /// assert_eq!(CheckDigit::Invalid.digit(), None);
+    /// ```
+    ///
+    pub const fn digit(&self) -> Option<i8> {
+        match self {
+            CheckDigit::Digit(digit) => Some(*digit),
+            CheckDigit::Invalid => None,
+        }
+    }
+
+    /// Is the check digit valid i.e. is the checksum not 10?
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use nhs_number::CheckDigit;
+    /// assert!(CheckDigit::Digit(7).is_valid());
+    /// assert!(!CheckDigit::Invalid.is_valid());
+    /// ```
+    ///
+    pub const fn is_valid(&self) -> bool {
+        matches!(self, CheckDigit::Digit(_))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_checksum() {
+        for checksum in 1..=9 {
+            assert_eq!(
+                CheckDigit::from_checksum(checksum),
+                CheckDigit::Digit(checksum as i8)
+            );
+        }
+        assert_eq!(CheckDigit::from_checksum(10), CheckDigit::Invalid);
+        assert_eq!(CheckDigit::from_checksum(11), CheckDigit::Digit(0));
+    }
+
+    #[test]
+    fn test_digit() {
+        assert_eq!(CheckDigit::Digit(0).digit(), Some(0));
+        assert_eq!(CheckDigit::Invalid.digit(), None);
+    }
+
+    #[test]
+    fn test_is_valid() {
+        assert!(CheckDigit::Digit(0).is_valid());
+        assert!(!CheckDigit::Invalid.is_valid());
+    }
+}
@@ -0,0 +1,344 @@
+//! Correction suggestions for a mistyped NHS Number, via the check digit.
+//!
+//! The modulus 11 check digit detects every single-digit substitution and
+//! every adjacent transposition, so when a NHS Number is invalid, the valid
+//! numbers that differ by one such error are good suggestions.
+//!
+//! The suggestions are for these kinds of error:
+//!
+//! * [CorrectionKind::Transposition] is two adjacent digits swapped.
+//!
+//! * [CorrectionKind::Substitution] is one digit mistyped.
+//!
+//! * [CorrectionKind::MissingDigit] is one digit left out, for input of nine
+//!   digits.
+//!
+//! * [CorrectionKind::ExtraDigit] is one digit typed twice or typed by
+//!   mistake, for input of eleven digits.
+//!
+//! The suggestions are ranked by kind in the order above, because a valid
+//! transposition is the least likely to be a coincidence, then by whether the
+//! number is in an issued range, then by the index of the error. Among the
+//! substitutions, a mistyped check digit ranks first, because it keeps the
+//! body of the number as typed.
+//!
+//! Example:
+//!
+//! ```rust
+//! use nhs_number::NHSNumber;
+//! use nhs_number::correction::CorrectionKind;
+//!
+//! let corrections = NHSNumber::suggest_corrections("999 345 6870");
+//! assert_eq!(corrections[0].nhs_number.to_string(), "999 345 6780");
+//! assert_eq!(corrections[0].kind, CorrectionKind::Transposition { index: 7 });
+//! ```
+
+use crate::NHSNumber;
+use crate::parse_options::LENIENT_SEPARATORS;
+use alloc::vec::Vec;
+
+/// Correction suggestion, which is a valid NHS Number and the kind of error
+/// that it corrects.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Correction {
+    pub nhs_number: NHSNumber,
+    pub kind: CorrectionKind,
+}
+
+/// The kind of error that a correction corrects.
+///
+/// Each index is the index of a digit in the input, ignoring separators.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub enum CorrectionKind {
+    /// The digits at this index and the next index were swapped.
+    Transposition { index: usize },
+
+    /// The digit at this index was mistyped.
+    Substitution { index: usize },
+
+    /// A digit is missing before this index, or at the end if the index is 9.
+    MissingDigit { index: usize },
+
+    /// The digit at this index is extra.
+    ExtraDigit { index: usize },
+}
+
+/// Correction options, which limit the suggestions.
+///
+/// The default options allow any count of suggestions, in any range.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct CorrectionOptions {
+    limit: Option<usize>,
+    issuable_only: bool,
+}
+
+impl CorrectionOptions {
+    /// Create the default correction options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the maximum count of suggestions.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Set whether to suggest only numbers that pass the full validation and
+    /// are in a currently issued range.
+    pub fn issuable_only(mut self, issuable_only: bool) -> Self {
+        self.issuable_only = issuable_only;
+        self
+    }
+}
+
+/// Suggest corrections for the input, which is digits and optional separators.
+///
+/// Return no suggestions if the input is already a valid NHS Number, or has
+/// a character that is neither a digit nor a separator, or has fewer than 9
+/// or more than 11 digits.
+///
+/// Example:
+///
+/// ```rust
+/// use nhs_number::correction::{CorrectionKind, CorrectionOptions, suggest_corrections};
+///
+/// let corrections = suggest_corrections("999 345 678", CorrectionOptions::new());
+/// assert!(corrections.iter().any(|c| {
+///     c.nhs_number.to_string() == "999 345 6780"
+///         && c.kind == CorrectionKind::MissingDigit { index: 9 }
+/// }));
+/// ```
+///
+/// This function is called by the method
+/// [NHSNumber::suggest_corrections_with](crate::NHSNumber::suggest_corrections_with).
+///
+pub fn suggest_corrections(s: &str, options: CorrectionOptions) -> Vec<Correction> {
+    let mut digits = Vec::with_capacity(11);
+    for c in s.trim().chars() {
+        match c.to_digit(10) {
+            Some(digit) if c.is_ascii_digit() => digits.push(digit as i8),
+            _ if LENIENT_SEPARATORS.contains(&c) => {}
+            _ => return Vec::new(),
+        }
+    }
+    let mut corrections = Vec::new();
+    match digits.len() {
+        9 => {
+            for index in 0..=9 {
+                for digit in 0..=9 {
+                    let mut candidate = digits.clone();
+                    candidate.insert(index, digit);
+                    push(
+                        &mut corrections,
+                        &candidate,
+                        CorrectionKind::MissingDigit { index },
+                    );
+                }
+            }
+        }
+        10 => {
+            if let Ok(digits) = <[i8; 10]>::try_from(digits.as_slice())
+                && NHSNumber::try_new(digits).is_ok()
+            {
+                return Vec::new();
+            }
+            for index in 0..9 {
+                if digits[index] != digits[index + 1] {
+                    let mut candidate = digits.clone();
+                    candidate.swap(index, index + 1);
+                    push(
+                        &mut corrections,
+                        &candidate,
+                        CorrectionKind::Transposition { index },
+                    );
+                }
+            }
+            for index in 0..10 {
+                for digit in (0..=9).filter(|digit| *digit != digits[index]) {
+                    let mut candidate = digits.clone();
+                    candidate[index] = digit;
+                    push(
+                        &mut corrections,
+                        &candidate,
+                        CorrectionKind::Substitution { index },
+                    );
+                }
+            }
+        }
+        11 => {
+            for index in 0..11 {
+                let mut candidate = digits.clone();
+                candidate.remove(index);
+                push(
+                    &mut corrections,
+                    &candidate,
+                    CorrectionKind::ExtraDigit { index },
+                );
+            }
+        }
+        _ => {}
+    }
+    if options.issuable_only {
+        corrections.retain(|correction| {
+            correction.nhs_number.validate().is_ok() && correction.nhs_number.range().is_issued()
+        });
+    }
+    corrections.sort_by_key(|correction| {
+        let rank = match correction.kind {
+            CorrectionKind::Transposition { .. } => 0,
+            CorrectionKind::Substitution { .. } => 1,
+            CorrectionKind::MissingDigit { .. } => 2,
+            CorrectionKind::ExtraDigit { .. } => 3,
+        };
+        let check_digit = matches!(correction.kind, CorrectionKind::Substitution { index: 9 });
+        (
+            rank,
+            !check_digit,
+            !correction.nhs_number.range().is_issued(),
+            correction.kind,
+        )
+    });
+    if let Some(limit) = options.limit {
+        corrections.truncate(limit);
+    }
+    corrections
+}
+
+/// Push the candidate digits as a correction, if they are a valid NHS Number
+/// that is not already a correction.
+fn push(corrections: &mut Vec<Correction>, candidate: &[i8], kind: CorrectionKind) {
+    if let Ok(digits) = <[i8; 10]>::try_from(candidate)
+        && let Ok(nhs_number) = NHSNumber::try_new(digits)
+        && !corrections
+            .iter()
+            .any(|correction| correction.nhs_number == nhs_number)
+    {
+        corrections.push(Correction { nhs_number, kind });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Suggest corrections with the default options, and return each
+    /// correction as a string and its kind.
+    fn suggest(s: &str) -> Vec<(String, CorrectionKind)> {
+        suggest_corrections(s, CorrectionOptions::default())
+            .into_iter()
+            .map(|correction| (correction.nhs_number.to_string(), correction.kind))
+            .collect()
+    }
+
+    #[test]
+    fn test_transposition() {
+        let corrections = suggest("9993456870");
+        assert_eq!(
+            corrections[0],
+            (
+                "999 345 6780".to_string(),
+                CorrectionKind::Transposition { index: 7 }
+            )
+        );
+    }
+
+    #[test]
+    fn test_substitution() {
+        let corrections = suggest("999 345 6880");
+        assert!(corrections.contains(&(
+            "999 345 6780".to_string(),
+            CorrectionKind::Substitution { index: 7 }
+        )));
+        assert!(corrections.iter().all(|(_, kind)| matches!(
+            kind,
+            CorrectionKind::Transposition { .. } | CorrectionKind::Substitution { .. }
+        )));
+    }
+
+    #[test]
+    fn test_substitution_of_check_digit() {
+        let corrections = suggest("999 345 6781");
+        assert!(corrections.contains(&(
+            "999 345 6780".to_string(),
+            CorrectionKind::Substitution { index: 9 }
+        )));
+    }
+
+    #[test]
+    fn test_ranking_prefers_check_digit_substitution() {
+        let corrections = suggest("9993456781");
+        assert_eq!(
+            corrections[0],
+            (
+                "999 345 6780".to_string(),
+                CorrectionKind::Substitution { index: 9 }
+            )
+        );
+    }
+
+    #[test]
+    fn test_missing_digit() {
+        let corrections = suggest("999 345 780");
+        assert!(corrections.contains(&(
+            "999 345 6780".to_string(),
+            CorrectionKind::MissingDigit { index: 6 }
+        )));
+    }
+
+    #[test]
+    fn test_extra_digit() {
+        let corrections = suggest("999 345 66780");
+        assert!(corrections.contains(&(
+            "999 345 6780".to_string(),
+            CorrectionKind::ExtraDigit { index: 6 }
+        )));
+    }
+
+    #[test]
+    fn test_no_duplicates() {
+        let corrections = suggest_corrections("99934567800", CorrectionOptions::default());
+        let mut numbers: Vec<NHSNumber> = corrections.iter().map(|c| c.nhs_number).collect();
+        let len = numbers.len();
+        numbers.sort();
+        numbers.dedup();
+        assert_eq!(numbers.len(), len);
+    }
+
+    #[test]
+    fn test_ranking_prefers_issued_ranges() {
+        let corrections = suggest_corrections("4010232136", CorrectionOptions::default());
+        let substitutions: Vec<&Correction> = corrections
+            .iter()
+            .filter(|c| matches!(c.kind, CorrectionKind::Substitution { .. }))
+            .collect();
+        let first_unissued = substitutions
+            .iter()
+            .position(|c| !c.nhs_number.range().is_issued())
+            .unwrap_or(substitutions.len());
+        assert!(
+            substitutions[first_unissued..]
+                .iter()
+                .all(|c| !c.nhs_number.range().is_issued())
+        );
+    }
+
+    #[test]
+    fn test_issuable_only_and_limit() {
+        let options = CorrectionOptions::new().issuable_only(true);
+        let corrections = suggest_corrections("401 023 2136", options);
+        assert!(!corrections.is_empty());
+        assert!(corrections.iter().all(|c| c.nhs_number.range().is_issued()));
+        let options = CorrectionOptions::new().limit(2);
+        assert_eq!(suggest_corrections("999 345 6781", options).len(), 2);
+    }
+
+    #[test]
+    fn test_no_suggestions() {
+        assert!(suggest("999 345 6780").is_empty());
+        assert!(suggest("999 34X 6780").is_empty());
+        assert!(suggest("12345678").is_empty());
+        assert!(suggest("123456789012").is_empty());
+        assert!(suggest("").is_empty());
+    }
+}
@@ -0,0 +1,448 @@
+//! Streaming CSV processor, which validates a NHS Number column, reports each
+//! invalid row with its reason, and writes a cleaned file with each NHS Number
+//! in a normalised display style.
+//!
+//! This module needs the feature `csv`.
+//!
+//! Example:
+//!
+//! ```rust
+//! use nhs_number::csv::{Column, CsvProcessor, RowErrorKind};
+//! use nhs_number::style::Style;
+//!
+//! let input = "id,nhs_number\n1,9993456780\n2,999 345 6781\n3,999-345-6780\n";
+//! let mut output = Vec::new();
+//! let mut reports = Vec::new();
+//!
+//! let summary = CsvProcessor::new(Column::Name("nhs_number".into()))
+//!     .style(Style::Spaced)
+//!     .process(input.as_bytes(), &mut output, |report| reports.push(report))
+//!     .unwrap();
+//!
+//! assert_eq!(String::from_utf8(output).unwrap(), "id,nhs_number\n1,999 345 6780\n");
+//! assert_eq!((summary.rows, summary.valid, summary.invalid), (3, 1, 2));
+//! assert_eq!(reports[0].line, 3);
+//! assert_eq!(reports[0].error.kind(), RowErrorKind::CheckDigit);
+//! assert_eq!(reports[1].error.kind(), RowErrorKind::Malformed);
+//! ```
+//!
+//! By default, the processor checks only the format and the check digit, so a
+//! row in a range that is not issuable is valid. To report such rows, turn on
+//! the full validation:
+//!
+//! ```rust
+//! use nhs_number::csv::{Column, CsvProcessor, RowErrorKind};
+//!
+//! let input = "nhs_number\n9434765919\n";
+//! let mut reports = Vec::new();
+//!
+//! let summary = CsvProcessor::new(Column::Index(0))
+//!     .full(true)
+//!     .process(input.as_bytes(), std::io::sink(), |report| reports.push(report))
+//!     .unwrap();
+//!
+//! assert_eq!(summary.invalid, 1);
+//! assert_eq!(reports[0].error.kind(), RowErrorKind::NotIssuable);
+//! ```
+
+use crate::parse_error::ParseError;
+use crate::parse_options::ParseOptions;
+use crate::style::Style;
+use crate::{NHSNumber, ValidationError};
+use ::csv::{ReaderBuilder, StringRecord, WriterBuilder};
+use std::fmt;
+use std::io;
+
+/// The column that holds the NHS Number.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Column {
+    /// The column with this header name, after trimming the header.
+    Name(String),
+
+    /// The column at this index, starting at zero.
+    Index(usize),
+}
+
+/// Streaming CSV processor, which reads one record at a time, so the size of
+/// the file does not matter.
+///
+/// The first record is the header, which is written unchanged. Each valid row
+/// is written with its NHS Number in the display style. Each invalid row is
+/// reported, and by default is not written.
+///
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct CsvProcessor<'a> {
+    column: Column,
+    options: ParseOptions<'a>,
+    style: Style,
+    full: bool,
+    keep_invalid: bool,
+    delimiter: u8,
+}
+
+impl<'a> CsvProcessor<'a> {
+    /// Create a CSV processor for the column, with strict parsing, the
+    /// compact style, the comma delimiter, and without the full validation.
+    pub fn new(column: Column) -> Self {
+        CsvProcessor {
+            column,
+            options: ParseOptions::strict(),
+            style: Style::Compact,
+            full: false,
+            keep_invalid: false,
+            delimiter: b',',
+        }
+    }
+
+    /// Set the parse options, such as lenient parsing for messy extracts.
+    pub fn options(mut self, options: ParseOptions<'a>) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Set the display style of each NHS Number in the cleaned file.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set whether to do the full validation, which also rejects repeated
+    /// digits, and ranges that are not issuable. The default is false, so
+    /// without this, no row is reported as not issuable.
+    pub fn full(mut self, full: bool) -> Self {
+        self.full = full;
+        self
+    }
+
+    /// Set whether to write each invalid row unchanged to the cleaned file.
+    pub fn keep_invalid(mut self, keep_invalid: bool) -> Self {
+        self.keep_invalid = keep_invalid;
+        self
+    }
+
+    /// Set the field delimiter, such as `b'\t'` for tab-separated values.
+    pub fn delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Check one NHS Number field.
+    pub fn check(&self, input: &str) -> Result<NHSNumber, RowError> {
+        let nhs_number = self.options.parse(input).map_err(RowError::Parse)?;
+        if self.full {
+            nhs_number.validate().map_err(RowError::Validation)?;
+        }
+        Ok(nhs_number)
+    }
+
+    /// Process the CSV reader into the cleaned CSV writer, and call `report`
+    /// for each invalid row.
+    ///
+    /// Return a summary of the counts of rows, or an error if the CSV is
+    /// malformed, or the column is missing, or reading or writing fails.
+    pub fn process<R: io::Read, W: io::Write>(
+        &self,
+        reader: R,
+        writer: W,
+        mut report: impl FnMut(RowReport),
+    ) -> Result<Summary, CsvError> {
+        let mut reader = ReaderBuilder::new()
+            .delimiter(self.delimiter)
+            .from_reader(reader);
+        let mut writer = WriterBuilder::new()
+            .delimiter(self.delimiter)
+            .from_writer(writer);
+        let headers = reader.headers()?.clone();
+        let index = self.column_index(&headers)?;
+        writer.write_record(&headers)?;
+        let mut summary = Summary::default();
+        let mut record = StringRecord::new();
+        while reader.read_record(&mut record)? {
+            summary.rows += 1;
+            let input = &record[index];
+            match self.check(input) {
+                Ok(nhs_number) => {
+                    summary.valid += 1;
+                    let cleaned = nhs_number.display_with(self.style).to_string();
+                    writer.write_record(record.iter().enumerate().map(|(i, field)| {
+                        if i == index { cleaned.as_str() } else { field }
+                    }))?;
+                }
+                Err(error) => {
+                    summary.invalid += 1;
+                    report(RowReport {
+                        line: record.position().map_or(0, |position| position.line()),
+                        input: input.to_string(),
+                        error,
+                    });
+                    if self.keep_invalid {
+                        writer.write_record(&record)?;
+                    }
+                }
+            }
+        }
+        writer.flush()?;
+        Ok(summary)
+    }
+
+    /// Find the index of the column in the headers.
+    fn column_index(&self, headers: &StringRecord) -> Result<usize, CsvError> {
+        match &self.column {
+            Column::Name(name) => headers
+                .iter()
+                .position(|header| header.trim() == name)
+                .ok_or_else(|| CsvError::MissingColumn { name: name.clone() }),
+            Column::Index(index) if *index < headers.len() => Ok(*index),
+            Column::Index(index) => Err(CsvError::MissingColumnIndex {
+                index: *index,
+                len: headers.len(),
+            }),
+        }
+    }
+}
+
+/// Summary of the counts of rows, excluding the header.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct Summary {
+    pub rows: u64,
+    pub valid: u64,
+    pub invalid: u64,
+}
+
+/// Report of an invalid row.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct RowReport {
+    /// The line number in the CSV input, starting at 1 for the header.
+    pub line: u64,
+
+    /// The NHS Number field, as it is in the CSV input.
+    pub input: String,
+
+    /// The reason the row is invalid.
+    pub error: RowError,
+}
+
+/// The reason a row is invalid, which is the detailed parse error or
+/// validation error.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum RowError {
+    /// The field is not a NHS Number, or has the wrong check digit.
+    Parse(ParseError),
+
+    /// The NHS Number fails the full validation.
+    Validation(ValidationError),
+}
+
+/// The kind of reason a row is invalid, which is for summary reports.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum RowErrorKind {
+    /// The field has the wrong length, or a wrong character or separator.
+    Malformed,
+
+    /// The field has the wrong check digit.
+    CheckDigit,
+
+    /// The NHS Number has repeated digits, or is in a range that is not issuable.
+    NotIssuable,
+}
+
+impl RowError {
+    /// Get the kind of reason.
+    pub fn kind(&self) -> RowErrorKind {
+        match self {
+            RowError::Parse(ParseError::InvalidCheckDigit { .. }) => RowErrorKind::CheckDigit,
+            RowError::Parse(_) => RowErrorKind::Malformed,
+            RowError::Validation(ValidationError::InvalidCheckDigit { .. }) => {
+                RowErrorKind::CheckDigit
+            }
+            RowError::Validation(_) => RowErrorKind::NotIssuable,
+        }
+    }
+}
+
+impl fmt::Display for RowError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RowError::Parse(error) => error.fmt(f),
+            RowError::Validation(error) => error.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for RowError {}
+
+impl fmt::Display for RowErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            RowErrorKind::Malformed => "malformed",
+            RowErrorKind::CheckDigit => "check digit",
+            RowErrorKind::NotIssuable => "not issuable",
+        })
+    }
+}
+
+/// CSV processing error, which stops the processing.
+#[derive(Debug)]
+pub enum CsvError {
+    /// The CSV is malformed, or reading or writing fails.
+    Csv(::csv::Error),
+
+    /// The headers have no column with this name.
+    MissingColumn { name: String },
+
+    /// The headers have fewer columns than this index needs.
+    MissingColumnIndex { index: usize, len: usize },
+}
+
+impl fmt::Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CsvError::Csv(error) => error.fmt(f),
+            CsvError::MissingColumn { name } => write!(f, "missing column {:?}", name),
+            CsvError::MissingColumnIndex { index, len } => write!(
+                f,
+                "missing column at index {}: found {} columns",
+                index, len
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CsvError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CsvError::Csv(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<::csv::Error> for CsvError {
+    fn from(error: ::csv::Error) -> Self {
+        CsvError::Csv(error)
+    }
+}
+
+impl From<io::Error> for CsvError {
+    fn from(error: io::Error) -> Self {
+        CsvError::Csv(error.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CheckDigit, IssuingRange};
+
+    /// Process the input, and return the output, the reports, and the summary.
+    fn process(processor: &CsvProcessor, input: &str) -> (String, Vec<RowReport>, Summary) {
+        let mut output = Vec::new();
+        let mut reports = Vec::new();
+        let summary = processor
+            .process(input.as_bytes(), &mut output, |report| reports.push(report))
+            .unwrap();
+        (String::from_utf8(output).unwrap(), reports, summary)
+    }
+
+    #[test]
+    fn test_process() {
+        let processor = CsvProcessor::new(Column::Name("NHS Number".into()));
+        let input = "Name, NHS Number \nAlice,999 345 6780\nBob,999-345-6780\nCarol,9434765919\n";
+        let (output, reports, summary) = process(&processor, input);
+        assert_eq!(
+            output,
+            "Name, NHS Number \nAlice,9993456780\nCarol,9434765919\n"
+        );
+        assert_eq!(
+            reports,
+            [RowReport {
+                line: 3,
+                input: "999-345-6780".into(),
+                error: RowError::Parse(ParseError::MisplacedSeparator {
+                    character: '-',
+                    offset: 3
+                }),
+            }]
+        );
+        assert_eq!(
+            summary,
+            Summary {
+                rows: 3,
+                valid: 2,
+                invalid: 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_process_with_options() {
+        let processor = CsvProcessor::new(Column::Index(1))
+            .options(ParseOptions::lenient())
+            .style(Style::Hyphenated)
+            .delimiter(b'\t');
+        let input = "name\tnhs\nAlice\tNHS No: 999 345 6780\n";
+        let (output, reports, _) = process(&processor, input);
+        assert_eq!(output, "name\tnhs\nAlice\t999-345-6780\n");
+        assert!(reports.is_empty());
+    }
+
+    #[test]
+    fn test_process_full() {
+        let processor = CsvProcessor::new(Column::Index(0)).full(true);
+        let (output, reports, _) = process(&processor, "nhs\n9434765919\n");
+        assert_eq!(output, "nhs\n");
+        assert_eq!(
+            reports[0].error,
+            RowError::Validation(ValidationError::OutOfRange {
+                range: IssuingRange::Unallocated
+            })
+        );
+        assert_eq!(reports[0].error.kind(), RowErrorKind::NotIssuable);
+    }
+
+    #[test]
+    fn test_process_keep_invalid() {
+        let processor = CsvProcessor::new(Column::Index(0)).keep_invalid(true);
+        let (output, reports, _) = process(&processor, "nhs\nnot a number\n999 345 6780\n");
+        assert_eq!(output, "nhs\nnot a number\n9993456780\n");
+        assert_eq!(reports[0].error.kind(), RowErrorKind::Malformed);
+    }
+
+    #[test]
+    fn test_process_with_missing_column() {
+        let processor = CsvProcessor::new(Column::Name("nhs".into()));
+        let error = processor
+            .process("id\n1\n".as_bytes(), io::sink(), |_| {})
+            .unwrap_err();
+        assert_eq!(error.to_string(), "missing column \"nhs\"");
+        let processor = CsvProcessor::new(Column::Index(2));
+        let error = processor
+            .process("id\n1\n".as_bytes(), io::sink(), |_| {})
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "missing column at index 2: found 1 columns"
+        );
+    }
+
+    #[test]
+    fn test_process_with_malformed_csv() {
+        let processor = CsvProcessor::new(Column::Index(0));
+        let result = processor.process("a,b\n1\n".as_bytes(), io::sink(), |_| {});
+        assert!(matches!(result, Err(CsvError::Csv(_))));
+    }
+
+    #[test]
+    fn test_row_error() {
+        let error = RowError::Parse(ParseError::InvalidCheckDigit {
+            expected: CheckDigit::Digit(0),
+            actual: 1,
+        });
+        assert_eq!(error.kind(), RowErrorKind::CheckDigit);
+        assert_eq!(
+            error.to_string(),
+            "invalid check digit: expected 0, found 1"
+        );
+        assert_eq!(RowErrorKind::NotIssuable.to_string(), "not issuable");
+    }
+}
@@ -1,6 +1,7 @@
 use crate::NHSNumber;
 use crate::parse_error::ParseError;
-use std::str::FromStr;
+use crate::parse_options::ParseOptions;
+use core::str::FromStr;
 
 /// Implement the `FromStr` trait for NHSNumber to allow parsing from a string.
 ///
@@ -13,6 +14,11 @@ use std::str::FromStr;
 ///
 /// - 12 characters with spaces e.g. "012 345 6789".
 ///
+/// The parser also verifies the check digit.
+///
+/// For messy real-world input, such as hyphen separators or label prefixes,
+/// see [ParseOptions::lenient].
+///
 /// Example:
 ///
 /// ```rust
@@ -20,53 +26,35 @@ use std::str::FromStr;
 /// use std::str::FromStr;
 /// let nhs_number_str = "012 345 6789";
 /// let nhs_number: NHSNumber = NHSNumber::from_str(nhs_number_str).unwrap();
-/// assert_eq!(nhs_number.digits, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+/// assert_eq!(nhs_number.digits(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+/// ```
+///
+/// When parsing fails, the error explains why:
+///
+/// ```rust
+/// use nhs_number::{NHSNumber, parse_error::ParseError};
+/// use std::str::FromStr;
+/// let result = NHSNumber::from_str("012 345 678");
+/// assert_eq!(result, Err(ParseError::InvalidLength { expected: 12, actual: 11 }));
 /// ```
 ///
 impl FromStr for NHSNumber {
     type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let chars: Vec<char> = s.chars().collect();
-        match chars.len() {
-            10 => {
-                let mut digits: [i8; 10] = [0; 10];
-                for i in 0..10 {
-                    digits[i] = chars[i].to_digit(10).ok_or(ParseError)? as i8
-                }
-                Ok(NHSNumber { digits: digits })
-            }
-            12 => {
-                if chars[3] != ' ' || chars[7] != ' ' {
-                    return Err(ParseError);
-                }
-                let mut digits: [i8; 10] = [0; 10];
-                for i in 0..3 {
-                    digits[i] = chars[i].to_digit(10).ok_or(ParseError)? as i8
-                }
-                for i in 0..3 {
-                    digits[i + 3] = chars[i + 4].to_digit(10).ok_or(ParseError)? as i8
-                }
-                for i in 0..4 {
-                    digits[i + 6] = chars[i + 8].to_digit(10).ok_or(ParseError)? as i8
-                }
-                Ok(NHSNumber { digits: digits })
-            }
-            _ => {
-                return Err(ParseError);
-            }
-        }
+        ParseOptions::strict().parse(s)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::CheckDigit;
 
     #[test]
     fn test_from_str_with_length_10_without_spaces() {
         let s = String::from("0123456789");
         let actual: NHSNumber = NHSNumber::from_str(&s).unwrap();
-        let expect: NHSNumber = NHSNumber::new([0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        let expect: NHSNumber = NHSNumber::new_unchecked([0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
         assert_eq!(actual, expect);
     }
 
@@ -74,7 +62,7 @@ mod tests {
     fn test_from_str_with_length_12_with_spaces() {
         let s = String::from("012 345 6789");
         let actual: NHSNumber = NHSNumber::from_str(&s).unwrap();
-        let expect: NHSNumber = NHSNumber::new([0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        let expect: NHSNumber = NHSNumber::new_unchecked([0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
         assert_eq!(actual, expect);
     }
 
@@ -82,48 +70,142 @@ mod tests {
     fn test_from_str_with_wrong_characters() {
         let s = String::from("012-345-6789");
         let result: Result<NHSNumber, ParseError> = NHSNumber::from_str(&s);
-        assert!(result.is_err());
+        assert_eq!(
+            result,
+            Err(ParseError::MisplacedSeparator {
+                character: '-',
+                offset: 3
+            })
+        );
     }
 
     #[test]
     fn test_from_str_with_wrong_leading_space() {
         let s = String::from(" 012 345 6789");
         let result: Result<NHSNumber, ParseError> = NHSNumber::from_str(&s);
-        assert!(result.is_err());
+        assert_eq!(
+            result,
+            Err(ParseError::InvalidLength {
+                expected: 12,
+                actual: 13
+            })
+        );
     }
 
     #[test]
     fn test_from_str_with_first_space_without_last_space() {
         let s = String::from("012 3456789");
         let result: Result<NHSNumber, ParseError> = NHSNumber::from_str(&s);
-        assert!(result.is_err());
+        assert_eq!(
+            result,
+            Err(ParseError::InvalidLength {
+                expected: 12,
+                actual: 11
+            })
+        );
     }
 
     #[test]
     fn test_from_str_without_first_space_with_last_space() {
         let s = String::from("012345 6789");
         let result: Result<NHSNumber, ParseError> = NHSNumber::from_str(&s);
-        assert!(result.is_err());
+        assert_eq!(
+            result,
+            Err(ParseError::InvalidLength {
+                expected: 12,
+                actual: 11
+            })
+        );
     }
 
     #[test]
     fn test_from_str_with_wrong_inner_space() {
         let s = String::from("012  345  6789");
         let result: Result<NHSNumber, ParseError> = NHSNumber::from_str(&s);
-        assert!(result.is_err());
+        assert_eq!(
+            result,
+            Err(ParseError::InvalidLength {
+                expected: 12,
+                actual: 14
+            })
+        );
     }
 
     #[test]
     fn test_from_str_with_wrong_trailing_space() {
         let s = String::from("012 345 6789 ");
         let result: Result<NHSNumber, ParseError> = NHSNumber::from_str(&s);
-        assert!(result.is_err());
+        assert_eq!(
+            result,
+            Err(ParseError::InvalidLength {
+                expected: 12,
+                actual: 13
+            })
+        );
     }
 
     #[test]
     fn test_from_str_with_wrong_length() {
         let s = String::from("012");
         let result: Result<NHSNumber, ParseError> = NHSNumber::from_str(&s);
-        assert!(result.is_err());
+        assert_eq!(
+            result,
+            Err(ParseError::InvalidLength {
+                expected: 10,
+                actual: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_from_str_with_wrong_character() {
+        let s = String::from("012 3X5 6789");
+        let result: Result<NHSNumber, ParseError> = NHSNumber::from_str(&s);
+        assert_eq!(
+            result,
+            Err(ParseError::InvalidCharacter {
+                character: 'X',
+                offset: 5
+            })
+        );
+    }
+
+    #[test]
+    fn test_from_str_with_wrong_multibyte_character() {
+        let s = String::from("01é3456789");
+        let result: Result<NHSNumber, ParseError> = NHSNumber::from_str(&s);
+        assert_eq!(
+            result,
+            Err(ParseError::InvalidCharacter {
+                character: 'é',
+                offset: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_from_str_with_wrong_check_digit() {
+        let s = String::from("999 345 6781");
+        let result: Result<NHSNumber, ParseError> = NHSNumber::from_str(&s);
+        assert_eq!(
+            result,
+            Err(ParseError::InvalidCheckDigit {
+                expected: CheckDigit::Digit(0),
+                actual: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_from_str_with_invalid_checksum() {
+        let s = String::from("999 123 4560");
+        let result: Result<NHSNumber, ParseError> = NHSNumber::from_str(&s);
+        assert_eq!(
+            result,
+            Err(ParseError::InvalidCheckDigit {
+                expected: CheckDigit::Invalid,
+                actual: 0
+            })
+        );
     }
 }
@@ -0,0 +1,274 @@
+use crate::{CheckDigit, NHSNumber, ValidationError};
+use core::fmt;
+
+/// The largest ten-digit integer.
+const MAX_U64: u64 = 9_999_999_999;
+
+/// The largest nine-digit integer.
+const MAX_BODY: u32 = 999_999_999;
+
+/// Convert the NHS Number into an integer, which is lossless because a NHS
+/// Number always has ten digits, so any leading zeros are implied.
+///
+/// Example:
+///
+/// ```rust
+/// use nhs_number::NHSNumber;
+/// let nhs_number = NHSNumber::try_new([9, 9, 9, 3, 4, 5, 6, 7, 8, 0]).unwrap();
+/// assert_eq!(u64::from(nhs_number), 9_993_456_780);
+/// ```
+///
+impl From<NHSNumber> for u64 {
+    fn from(nhs_number: NHSNumber) -> Self {
+        nhs_number
+            .digits
+            .iter()
+            .fold(0, |value, &digit| value * 10 + digit as u64)
+    }
+}
+
+/// Convert an integer into a NHS Number, if the integer has at most ten
+/// digits and the check digit is correct.
+///
+/// Example:
+///
+/// ```rust
+/// use nhs_number::{NHSNumber, ValidationError};
+/// let nhs_number = NHSNumber::try_from(9_993_456_780u64).unwrap();
+/// assert_eq!(nhs_number.to_string(), "999 345 6780");
+///
+/// let result = NHSNumber::try_from(99_993_456_780u64);
+/// assert_eq!(result, Err(ValidationError::TooLarge { digits: 11 }));
+/// ```
+///
+impl TryFrom<u64> for NHSNumber {
+    type Error = ValidationError;
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        if value > MAX_U64 {
+            return Err(ValidationError::TooLarge {
+                digits: value.ilog10() as usize + 1,
+            });
+        }
+        let actual = (value % 10) as i8;
+        match NHSNumber::from_body_value((value / 10) as u32) {
+            Some(nhs_number) if nhs_number.check_digit() == actual => Ok(nhs_number),
+            Some(nhs_number) => Err(ValidationError::InvalidCheckDigit {
+                expected: CheckDigit::Digit(nhs_number.check_digit()),
+                actual,
+            }),
+            None => Err(ValidationError::InvalidCheckDigit {
+                expected: CheckDigit::Invalid,
+                actual,
+            }),
+        }
+    }
+}
+
+/// Packed NHS Number, which stores the body i.e. the first nine digits, in a
+/// `u32`, and derives the check digit when needed.
+///
+/// This is a compact representation for storage and for keys, such as in hash
+/// joins. The ordering of packed numbers is the same as the ordering of the
+/// NHS Numbers.
+///
+/// A packed NHS Number always has a valid check digit, because the conversions
+/// reject any number whose check digit is wrong, and any body that yields the
+/// invalid checksum 10.
+///
+/// Example:
+///
+/// ```rust
+/// use nhs_number::{NHSNumber, integer::PackedNHSNumber};
+/// let nhs_number = NHSNumber::try_new([9, 9, 9, 3, 4, 5, 6, 7, 8, 0]).unwrap();
+/// let packed = PackedNHSNumber::try_from(nhs_number).unwrap();
+/// assert_eq!(u32::from(packed), 999_345_678);
+/// assert_eq!(NHSNumber::from(packed), nhs_number);
+/// ```
+///
+/// The `Debug` implementation masks all but the last four digits, the same as
+/// the `Debug` implementation of NHS Number, because the body determines the
+/// whole NHS Number.
+///
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub struct PackedNHSNumber(u32);
+
+impl fmt::Debug for PackedNHSNumber {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PackedNHSNumber({})", NHSNumber::from(*self).masked())
+    }
+}
+
+impl PackedNHSNumber {
+    /// Get the body i.e. the first nine digits, as an integer.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use nhs_number::integer::PackedNHSNumber;
+    /// let packed = PackedNHSNumber::try_from(999_345_678).unwrap();
+    /// assert_eq!(packed.body(), 999_345_678);
+    /// ```
+    ///
+    pub fn body(&self) -> u32 {
+        self.0
+    }
+
+    /// Get the check digit, which is derived from the body.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use nhs_number::integer::PackedNHSNumber;
+    /// let packed = PackedNHSNumber::try_from(999_345_678).unwrap();
+    /// assert_eq!(packed.check_digit(), 0);
+    /// ```
+    ///
+    pub fn check_digit(&self) -> i8 {
+        NHSNumber::from(*self).check_digit()
+    }
+}
+
+/// Pack the NHS Number, if its check digit is correct.
+impl TryFrom<NHSNumber> for PackedNHSNumber {
+    type Error = ValidationError;
+    fn try_from(nhs_number: NHSNumber) -> Result<Self, Self::Error> {
+        NHSNumber::try_new(nhs_number.digits)?;
+        Ok(PackedNHSNumber(nhs_number.body()))
+    }
+}
+
+/// Unpack the NHS Number, by appending the derived check digit.
+impl From<PackedNHSNumber> for NHSNumber {
+    fn from(packed: PackedNHSNumber) -> Self {
+        NHSNumber::from_body_value(packed.0).expect("packed body has a valid check digit")
+    }
+}
+
+/// Convert a body i.e. the first nine digits as an integer, into a packed NHS
+/// Number, if the body has at most nine digits and does not yield the invalid
+/// checksum 10.
+///
+/// Example:
+///
+/// ```rust
+/// use nhs_number::{ValidationError, integer::PackedNHSNumber};
+/// assert!(PackedNHSNumber::try_from(999_345_678).is_ok());
+/// assert_eq!(
+///     PackedNHSNumber::try_from(999_123_456),
+///     Err(ValidationError::InvalidBody)
+/// );
+/// ```
+///
+impl TryFrom<u32> for PackedNHSNumber {
+    type Error = ValidationError;
+    fn try_from(body: u32) -> Result<Self, Self::Error> {
+        if body > MAX_BODY {
+            return Err(ValidationError::TooLarge {
+                digits: body.ilog10() as usize + 1,
+            });
+        }
+        match NHSNumber::from_body_value(body) {
+            Some(_) => Ok(PackedNHSNumber(body)),
+            None => Err(ValidationError::InvalidBody),
+        }
+    }
+}
+
+/// Convert the packed NHS Number into its body i.e. the first nine digits.
+impl From<PackedNHSNumber> for u32 {
+    fn from(packed: PackedNHSNumber) -> Self {
+        packed.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CheckDigit;
+
+    #[test]
+    fn test_u64_from_nhs_number() {
+        let a = NHSNumber::new_unchecked([0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        assert_eq!(u64::from(a), 123_456_789);
+        let a = NHSNumber::new_unchecked([9, 9, 9, 9, 9, 9, 9, 9, 9, 9]);
+        assert_eq!(u64::from(a), 9_999_999_999);
+    }
+
+    #[test]
+    fn test_nhs_number_try_from_u64() {
+        assert_eq!(
+            NHSNumber::try_from(123_456_789u64),
+            Ok(NHSNumber::new_unchecked([0, 1, 2, 3, 4, 5, 6, 7, 8, 9]))
+        );
+        assert_eq!(
+            NHSNumber::try_from(9_993_456_781u64),
+            Err(ValidationError::InvalidCheckDigit {
+                expected: CheckDigit::Digit(0),
+                actual: 1
+            })
+        );
+        assert_eq!(
+            NHSNumber::try_from(10_000_000_000u64),
+            Err(ValidationError::TooLarge { digits: 11 })
+        );
+    }
+
+    #[test]
+    fn test_u64_round_trip() {
+        for nhs_number in crate::testable::TESTABLE_RANGE.iter().take(1000) {
+            assert_eq!(NHSNumber::try_from(u64::from(nhs_number)), Ok(nhs_number));
+        }
+    }
+
+    #[test]
+    fn test_packed_round_trip() {
+        for nhs_number in crate::testable::TESTABLE_RANGE.iter().take(1000) {
+            let packed = PackedNHSNumber::try_from(nhs_number).unwrap();
+            assert_eq!(NHSNumber::from(packed), nhs_number);
+            assert_eq!(packed.check_digit(), nhs_number.check_digit());
+        }
+    }
+
+    #[test]
+    fn test_packed_try_from_nhs_number_with_wrong_check_digit() {
+        let a = NHSNumber::new_unchecked([9, 9, 9, 3, 4, 5, 6, 7, 8, 1]);
+        assert_eq!(
+            PackedNHSNumber::try_from(a),
+            Err(ValidationError::InvalidCheckDigit {
+                expected: CheckDigit::Digit(0),
+                actual: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_packed_try_from_u32() {
+        assert_eq!(
+            PackedNHSNumber::try_from(12_345_678).unwrap().body(),
+            12_345_678
+        );
+        assert_eq!(
+            PackedNHSNumber::try_from(1_000_000_000),
+            Err(ValidationError::TooLarge { digits: 10 })
+        );
+        assert_eq!(
+            PackedNHSNumber::try_from(999_123_456),
+            Err(ValidationError::InvalidBody)
+        );
+    }
+
+    #[test]
+    fn test_packed_debug() {
+        let packed = PackedNHSNumber::try_from(999_345_678).unwrap();
+        assert_eq!(format!("{:?}", packed), "PackedNHSNumber(*** *** 6780)");
+    }
+
+    #[test]
+    fn test_packed_ordering() {
+        let a = PackedNHSNumber::try_from(NHSNumber::new_unchecked([9, 9, 9, 0, 0, 0, 0, 0, 1, 8]))
+            .unwrap();
+        let b = PackedNHSNumber::try_from(NHSNumber::new_unchecked([9, 9, 9, 3, 4, 5, 6, 7, 8, 0]))
+            .unwrap();
+        assert!(a < b);
+    }
+}
@@ -0,0 +1,196 @@
+use core::fmt;
+use core::ops::RangeInclusive;
+
+/// NHS Number issuing range, which is the national system that a NHS Number
+/// belongs to, according to the number ranges in the crate documentation.
+///
+/// Where the documented ranges overlap, the unavailable ranges take
+/// precedence, so 300 000 0000 is a Scottish CHI number, and 320 000 0010 is a
+/// Northern Ireland H&C number.
+///
+/// Example:
+///
+/// ```rust
+/// use nhs_number::{IssuingRange, NHSNumber};
+/// let nhs_number = NHSNumber::try_new([4, 0, 1, 0, 2, 3, 2, 1, 3, 7]).unwrap();
+/// assert_eq!(nhs_number.range(), IssuingRange::EnglandWalesIsleOfMan);
+/// ```
+///
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum IssuingRange {
+    /// 311 300 0000 to 320 000 0009, which are issued in England.
+    England,
+
+    /// 400 000 0000 to 499 999 9999, and 600 000 0000 to 799 999 9999, which
+    /// are issued in England, Wales, and the Isle of Man.
+    EnglandWalesIsleOfMan,
+
+    /// 320 000 0010 to 399 999 9999, which are allocated to the Northern
+    /// Ireland Health and Care (H&C) system.
+    NorthernIrelandHAndC,
+
+    /// 010 100 0000 to 311 299 9999, which are used for Community Health
+    /// Index (CHI) numbers in Scotland.
+    ScottishChi,
+
+    /// 999 000 0000 to 999 999 9999, which are valid but are guaranteed to
+    /// never be issued, so are for test purposes.
+    Test,
+
+    /// Any other number, which is not in any documented range.
+    Unallocated,
+}
+
+/// The issuing ranges as ten-digit values, in precedence order.
+const RANGES: &[(RangeInclusive<u64>, IssuingRange)] = &[
+    (101000000..=3112999999, IssuingRange::ScottishChi),
+    (3200000010..=3999999999, IssuingRange::NorthernIrelandHAndC),
+    (3000000000..=3999999999, IssuingRange::England),
+    (4000000000..=4999999999, IssuingRange::EnglandWalesIsleOfMan),
+    (6000000000..=7999999999, IssuingRange::EnglandWalesIsleOfMan),
+    (9990000000..=9999999999, IssuingRange::Test),
+];
+
+impl IssuingRange {
+    /// Is this range currently issued to patients in England, Wales, or the
+    /// Isle of Man?
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use nhs_number::IssuingRange;
+    /// assert!(IssuingRange::England.is_issued());
+    /// assert!(!IssuingRange::Test.is_issued());
+    /// ```
+    ///
+    pub fn is_issued(&self) -> bool {
+        matches!(
+            self,
+            IssuingRange::England | IssuingRange::EnglandWalesIsleOfMan
+        )
+    }
+}
+
+impl fmt::Display for IssuingRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            IssuingRange::England => "England",
+            IssuingRange::EnglandWalesIsleOfMan => "England, Wales, Isle of Man",
+            IssuingRange::NorthernIrelandHAndC => "Northern Ireland H&C",
+            IssuingRange::ScottishChi => "Scottish CHI",
+            IssuingRange::Test => "Test",
+            IssuingRange::Unallocated => "Unallocated",
+        })
+    }
+}
+
+/// Classify the NHS Number digits into their issuing range.
+///
+/// Example:
+///
+/// ```rust
+/// use nhs_number::IssuingRange;
+/// let digits = [9, 9, 9, 3, 4, 5, 6, 7, 8, 0];
+/// assert_eq!(nhs_number::issuing_range(digits), IssuingRange::Test);
+/// ```
+///
+/// Digits that include any digit not in the range 0–9 are unallocated.
+///
+/// This function is called by the method [NHSNumber::range](crate::NHSNumber::range).
+///
+#[allow(dead_code)]
+pub fn issuing_range(digits: [i8; 10]) -> IssuingRange {
+    if digits.iter().any(|digit| !(0..=9).contains(digit)) {
+        return IssuingRange::Unallocated;
+    }
+    let value = digits
+        .iter()
+        .fold(0u64, |value, &digit| value * 10 + digit as u64);
+    RANGES
+        .iter()
+        .find(|(range, _)| range.contains(&value))
+        .map_or(IssuingRange::Unallocated, |(_, issuing_range)| {
+            *issuing_range
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_issuing_range() {
+        let cases = [
+            ([0, 0, 0, 0, 0, 0, 0, 0, 0, 0], IssuingRange::Unallocated),
+            ([0, 1, 0, 0, 9, 9, 9, 9, 9, 9], IssuingRange::Unallocated),
+            ([0, 1, 0, 1, 0, 0, 0, 0, 0, 0], IssuingRange::ScottishChi),
+            ([3, 1, 1, 2, 9, 9, 9, 9, 9, 9], IssuingRange::ScottishChi),
+            ([3, 1, 1, 3, 0, 0, 0, 0, 0, 0], IssuingRange::England),
+            ([3, 2, 0, 0, 0, 0, 0, 0, 0, 9], IssuingRange::England),
+            (
+                [3, 2, 0, 0, 0, 0, 0, 0, 1, 0],
+                IssuingRange::NorthernIrelandHAndC,
+            ),
+            (
+                [3, 9, 9, 9, 9, 9, 9, 9, 9, 9],
+                IssuingRange::NorthernIrelandHAndC,
+            ),
+            (
+                [4, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+                IssuingRange::EnglandWalesIsleOfMan,
+            ),
+            (
+                [4, 9, 9, 9, 9, 9, 9, 9, 9, 9],
+                IssuingRange::EnglandWalesIsleOfMan,
+            ),
+            ([5, 0, 0, 0, 0, 0, 0, 0, 0, 0], IssuingRange::Unallocated),
+            (
+                [6, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+                IssuingRange::EnglandWalesIsleOfMan,
+            ),
+            (
+                [7, 9, 9, 9, 9, 9, 9, 9, 9, 9],
+                IssuingRange::EnglandWalesIsleOfMan,
+            ),
+            ([8, 0, 0, 0, 0, 0, 0, 0, 0, 0], IssuingRange::Unallocated),
+            ([9, 9, 8, 9, 9, 9, 9, 9, 9, 9], IssuingRange::Unallocated),
+            ([9, 9, 9, 0, 0, 0, 0, 0, 0, 0], IssuingRange::Test),
+            ([9, 9, 9, 9, 9, 9, 9, 9, 9, 9], IssuingRange::Test),
+        ];
+        for (digits, expect) in cases {
+            assert_eq!(issuing_range(digits), expect, "{:?}", digits);
+        }
+    }
+
+    #[test]
+    fn test_issuing_range_with_out_of_range_digit() {
+        assert_eq!(issuing_range([-1; 10]), IssuingRange::Unallocated);
+        assert_eq!(
+            issuing_range([-1, 0, 0, 0, 0, 0, 0, 0, 0, 0]),
+            IssuingRange::Unallocated
+        );
+        assert_eq!(
+            issuing_range([4, 0, 0, 0, 0, 0, 0, 0, 0, 10]),
+            IssuingRange::Unallocated
+        );
+        assert_eq!(issuing_range([i8::MAX; 10]), IssuingRange::Unallocated);
+    }
+
+    #[test]
+    fn test_is_issued() {
+        assert!(IssuingRange::England.is_issued());
+        assert!(IssuingRange::EnglandWalesIsleOfMan.is_issued());
+        assert!(!IssuingRange::NorthernIrelandHAndC.is_issued());
+        assert!(!IssuingRange::ScottishChi.is_issued());
+        assert!(!IssuingRange::Test.is_issued());
+        assert!(!IssuingRange::Unallocated.is_issued());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            IssuingRange::EnglandWalesIsleOfMan.to_string(),
+            "England, Wales, Isle of Man"
+        );
+    }
+}
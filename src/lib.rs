@@ -27,7 +27,7 @@
 //! ## Syntax
 //!
 //! The current system uses a ten-digit number in '3 3 4' format with the final
-//! digit being an error-detecting checksum. Examples given include 987 654 4321.
+//! digit being an error-detecting checksum. An example is 999 345 6780.
 //!
 //! ## Ranges
 //!
@@ -49,6 +49,8 @@
 //!
 //! * 999 000 0000 to 999 999 9999
 //!
+//! The method [NHSNumber::range] classifies a NHS Number into these ranges.
+//!
 //! ## Checksum
 //!
 //! The checksum is calculated by multiplying each of the first nine digits by 11
@@ -77,10 +79,10 @@
 //! ```rust
 //! use nhs_number::*;
 //! use std::str::FromStr;
-//! 
+//!
 //! // NHS Number that we can use for testing purposes
-//! let str = "999 123 4560";
-//! 
+//! let str = "999 345 6780";
+//!
 //! // Create a new NHS Number by converting from a string.
 //! let nhs_number = NHSNumber::from_str(str).unwrap();
 //!
@@ -88,13 +90,67 @@
 //! let valid: bool = nhs_number.validate_check_digit();
 //! ```
 //!
-use serde::{Deserialize, Serialize};
-use std::fmt;
+//! ## Cargo features
+//!
+//! The core type, the parsers, the check digit, and `Display` need only
+//! `core`, so the crate is `no_std` when the default features are off:
+//!
+//! * `std` (default): the standard library, such as the testable range statics
+//!   and the random samples from the thread-local random number generator.
+//!
+//! * `alloc` (default via `std`): allocation, such as [format()], `String`
+//!   conversion, correction suggestions, and redaction.
+//!
+//! * `testable` (default): testable random sample generators, which need the
+//!   `rand` crate; the feature `rand` is an alias.
+//!
+//! * `serde` (default): serde derives and the serde helpers.
+//!
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "serde")]
+use ::serde::Serialize;
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt;
+
+pub mod check_digit;
+pub use check_digit::CheckDigit;
+#[cfg(feature = "alloc")]
+pub mod correction;
+#[cfg(feature = "alloc")]
+use correction::{Correction, CorrectionOptions};
+#[cfg(feature = "csv")]
+pub mod csv;
 pub mod from_str;
+pub mod integer;
+pub mod issuing_range;
+pub use issuing_range::{IssuingRange, issuing_range};
+pub mod literal;
+pub mod mask;
+use mask::{MaskPolicy, Masked};
 pub mod parse_error;
+use parse_error::ParseError;
+pub mod parse_options;
+#[cfg(feature = "pseudonym")]
+pub mod pseudonym;
+#[cfg(feature = "alloc")]
+pub mod redact;
+pub mod scan;
+pub use scan::scan;
+#[cfg(feature = "serde")]
+pub mod serde;
+pub mod style;
+use style::{Style, Styled};
 pub mod testable;
 pub use testable::*;
+pub mod validation_error;
+pub use validation_error::ValidationError;
 
 /// NHS Number is a unique identifier for patients in the National Health
 /// Service of England, Wales, and the Isle of Man.
@@ -107,39 +163,289 @@ pub use testable::*;
 ///
 /// ```rust
 /// use nhs_number::NHSNumber;
-/// let digits = [9, 9, 9, 1, 2, 3, 4, 5, 6, 0];
-/// let nhs_number = NHSNumber { digits: digits };
+/// let digits = [9, 9, 9, 3, 4, 5, 6, 7, 8, 0];
+/// let nhs_number = NHSNumber::try_new(digits).unwrap();
 /// ```
 ///
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Serialize, Deserialize)]
+/// The digits are private, so that a NHS Number created by a validated
+/// constructor such as [NHSNumber::try_new], [TryFrom], or [FromStr](std::str::FromStr)
+/// always has digits in the range 0–9 and a correct check digit. Even the
+/// constructor [NHSNumber::new_unchecked], which is for negative testing,
+/// rejects any digit that is not in the range 0–9.
+///
+/// The serde implementation of `Deserialize` validates too, so a payload with
+/// an out-of-range digit or a wrong check digit is an error.
+///
+/// The `Debug` implementation masks all but the last four digits, so that a
+/// stray `{:?}` in a log does not leak the full NHS Number.
+///
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct NHSNumber {
-    pub digits: [i8; 10],
+    digits: [i8; 10],
 }
 
 impl NHSNumber {
-    /// Create a new NHS Number instance with the provided digits.
+    /// Create a new NHS Number instance with the provided digits, without
+    /// validating the check digit.
+    ///
+    /// Deprecated, because the name does not say that the check digit is
+    /// unchecked. Use [NHSNumber::try_new], or [NHSNumber::new_unchecked].
+    ///
+    /// Panics if a digit is not in the range 0–9.
+    ///
+    #[deprecated(note = "use `NHSNumber::try_new`, or `NHSNumber::new_unchecked`")]
+    #[allow(dead_code)]
+    pub const fn new(digits: [i8; 10]) -> Self {
+        NHSNumber::new_unchecked(digits)
+    }
+
+    /// Create a new NHS Number instance with the provided digits, without
+    /// validating the check digit, such as for negative testing.
     ///
     /// Example:
     ///
     /// ```rust
     /// use nhs_number::NHSNumber;
-    /// let digits = [9, 9, 9, 1, 2, 3, 4, 5, 6, 0];
-    /// let nhs_number = NHSNumber::new(digits);
+    /// let nhs_number = NHSNumber::new_unchecked([9, 9, 9, 3, 4, 5, 6, 7, 8, 1]);
+    /// assert!(!nhs_number.validate_check_digit());
+    /// ```
+    ///
+    /// The digits are always in the range 0–9, even for a NHS Number with a
+    /// wrong check digit, so every method is well-defined. Prefer
+    /// [NHSNumber::try_new], which also validates the check digit.
+    ///
+    /// Panics if a digit is not in the range 0–9:
+    ///
+    /// ```should_panic
+    /// use nhs_number::NHSNumber;
+    /// let nhs_number = NHSNumber::new_unchecked([-3, 42, 9, 3, 4, 5, 6, 7, 8, 0]);
     /// ```
     ///
     #[allow(dead_code)]
-    pub fn new(digits: [i8; 10]) -> Self {
+    pub const fn new_unchecked(digits: [i8; 10]) -> Self {
+        let mut index = 0;
+        while index < 10 {
+            if digits[index] < 0 || digits[index] > 9 {
+                panic!("NHS Number digit must be in the range 0–9");
+            }
+            index += 1;
+        }
         NHSNumber { digits }
     }
 
+    /// Create a new NHS Number instance with the provided digits, if the
+    /// digits are each in the range 0–9 and the check digit is correct.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use nhs_number::{CheckDigit, NHSNumber, ValidationError};
+    ///
+    /// let result = NHSNumber::try_new([9, 9, 9, 3, 4, 5, 6, 7, 8, 0]);
+    /// assert!(result.is_ok());
+    ///
+    /// let result = NHSNumber::try_new([9, 9, 9, 3, 4, 5, 6, 7, 8, 1]);
+    /// assert_eq!(result, Err(ValidationError::InvalidCheckDigit {
+    ///     expected: CheckDigit::Digit(0),
+    ///     actual: 1,
+    /// }));
+    /// ```
+    ///
+    #[allow(dead_code)]
+    pub const fn try_new(digits: [i8; 10]) -> Result<Self, ValidationError> {
+        let mut index = 0;
+        while index < 10 {
+            let digit = digits[index];
+            if digit < 0 || digit > 9 {
+                return Err(ValidationError::InvalidDigit {
+                    index,
+                    digit: digit as i16,
+                });
+            }
+            index += 1;
+        }
+        match crate::calculate_check_digit(digits) {
+            CheckDigit::Digit(expected) if expected == digits[9] => Ok(NHSNumber { digits }),
+            expected => Err(ValidationError::InvalidCheckDigit {
+                expected,
+                actual: digits[9],
+            }),
+        }
+    }
+
+    /// Create a new NHS Number instance from a body i.e. the first nine
+    /// digits, by appending the calculated check digit.
+    ///
+    /// Return `None` if a digit is not in the range 0–9, or if the body yields
+    /// the invalid checksum 10, which no check digit can complete.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use nhs_number::NHSNumber;
+    ///
+    /// let nhs_number = NHSNumber::from_body([9, 9, 9, 3, 4, 5, 6, 7, 8]).unwrap();
+    /// assert_eq!(nhs_number.to_string(), "999 345 6780");
+    ///
+    /// let nhs_number = NHSNumber::from_body([9, 9, 9, 1, 2, 3, 4, 5, 6]);
+    /// assert_eq!(nhs_number, None);
+    /// ```
+    ///
+    /// This constructor calls the function [calculate_check_digit()].
+    ///
+    #[allow(dead_code)]
+    pub const fn from_body(body: [u8; 9]) -> Option<Self> {
+        let mut digits: [i8; 10] = [0; 10];
+        let mut i = 0;
+        while i < 9 {
+            if body[i] > 9 {
+                return None;
+            }
+            digits[i] = body[i] as i8;
+            i += 1;
+        }
+        match crate::calculate_check_digit(digits) {
+            CheckDigit::Digit(digit) => {
+                digits[9] = digit;
+                Some(NHSNumber { digits })
+            }
+            CheckDigit::Invalid => None,
+        }
+    }
+
+    /// Create a new NHS Number instance from a body string i.e. exactly nine
+    /// digits, by appending the calculated check digit.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use nhs_number::{NHSNumber, parse_error::ParseError};
+    ///
+    /// let nhs_number = NHSNumber::from_body_str("999345678").unwrap();
+    /// assert_eq!(nhs_number.to_string(), "999 345 6780");
+    ///
+    /// let result = NHSNumber::from_body_str("999123456");
+    /// assert_eq!(result, Err(ParseError::InvalidBody));
+    ///
+    /// let result = NHSNumber::from_body_str("99912345");
+    /// assert_eq!(result, Err(ParseError::InvalidLength { expected: 9, actual: 8 }));
+    /// ```
+    ///
+    /// This constructor calls the method [NHSNumber::from_body].
+    ///
+    #[allow(dead_code)]
+    pub fn from_body_str(s: &str) -> Result<Self, ParseError> {
+        let length = s.chars().count();
+        if length != 9 {
+            return Err(ParseError::InvalidLength {
+                expected: 9,
+                actual: length,
+            });
+        }
+        let mut body: [u8; 9] = [0; 9];
+        for (i, (offset, character)) in s.char_indices().enumerate() {
+            match character.to_digit(10) {
+                Some(digit) if character.is_ascii_digit() => body[i] = digit as u8,
+                _ => return Err(ParseError::InvalidCharacter { character, offset }),
+            }
+        }
+        NHSNumber::from_body(body).ok_or(ParseError::InvalidBody)
+    }
+
+    /// Get the NHS Number digits.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use nhs_number::NHSNumber;
+    /// let digits = [9, 9, 9, 3, 4, 5, 6, 7, 8, 0];
+    /// let nhs_number = NHSNumber::try_new(digits).unwrap();
+    /// assert_eq!(nhs_number.digits(), digits);
+    /// ```
+    ///
+    #[allow(dead_code)]
+    pub const fn digits(&self) -> [i8; 10] {
+        self.digits
+    }
+
+    /// Display the NHS Number with a style, such as compact or hyphenated,
+    /// without allocating.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use nhs_number::{NHSNumber, style::Style};
+    /// let nhs_number = NHSNumber::try_new([9, 9, 9, 3, 4, 5, 6, 7, 8, 0]).unwrap();
+    /// assert_eq!(nhs_number.display_with(Style::Hyphenated).to_string(), "999-345-6780");
+    /// ```
+    ///
+    #[allow(dead_code)]
+    pub fn display_with(&self, style: Style) -> Styled {
+        Styled::new(*self, style)
+    }
+
+    /// Display the NHS Number with all but the last four digits masked e.g.
+    /// "*** *** 6780", such as for logs and user interfaces.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use nhs_number::NHSNumber;
+    /// let nhs_number = NHSNumber::try_new([9, 9, 9, 3, 4, 5, 6, 7, 8, 0]).unwrap();
+    /// assert_eq!(nhs_number.masked().to_string(), "*** *** 6780");
+    /// ```
+    ///
+    #[allow(dead_code)]
+    pub fn masked(&self) -> Masked {
+        Masked::new(*self, MaskPolicy::default())
+    }
+
+    /// Display the NHS Number masked by a mask policy.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use nhs_number::{NHSNumber, mask::MaskPolicy};
+    /// let nhs_number = NHSNumber::try_new([9, 9, 9, 3, 4, 5, 6, 7, 8, 0]).unwrap();
+    /// assert_eq!(nhs_number.masked_with(MaskPolicy::leading(3)).to_string(), "999 *** ****");
+    /// ```
+    ///
+    #[allow(dead_code)]
+    pub fn masked_with(&self, policy: MaskPolicy) -> Masked {
+        Masked::new(*self, policy)
+    }
+
+    /// Get the NHS Number body i.e. the first nine digits, as an integer.
+    pub(crate) fn body(&self) -> u32 {
+        self.digits
+            .iter()
+            .take(9)
+            .fold(0, |body, &digit| body * 10 + digit as u32)
+    }
+
+    /// Create a NHS Number from a body i.e. the first nine digits as an
+    /// integer, by appending the calculated check digit, or return `None` if
+    /// the body yields the invalid checksum 10.
+    pub(crate) fn from_body_value(body: u32) -> Option<NHSNumber> {
+        debug_assert!(body < 1_000_000_000);
+        let mut digits: [u8; 9] = [0; 9];
+        let mut rest = body;
+        for i in (0..9).rev() {
+            digits[i] = (rest % 10) as u8;
+            rest /= 10;
+        }
+        NHSNumber::from_body(digits)
+    }
+
     /// Get the NHS Number check digit i.e. the last digit.
     ///
     /// Example:
     ///
     /// ```rust
     /// use nhs_number::NHSNumber;
-    /// let digits = [9, 9, 9, 1, 2, 3, 4, 5, 6, 0];
-    /// let nhs_number = NHSNumber::new(digits);
+    /// let digits = [9, 9, 9, 3, 4, 5, 6, 7, 8, 0];
+    /// let nhs_number = NHSNumber::try_new(digits).unwrap();
     /// let check_digit = nhs_number.check_digit();
     /// assert_eq!(check_digit, 0);
     /// ```
@@ -147,7 +453,7 @@ impl NHSNumber {
     /// This method calls the function [check_digit()].
     ///
     #[allow(dead_code)]
-    pub fn check_digit(&self) -> i8 {
+    pub const fn check_digit(&self) -> i8 {
         crate::check_digit(self.digits)
     }
 
@@ -156,17 +462,17 @@ impl NHSNumber {
     /// Example:
     ///
     /// ```rust
-    /// use nhs_number::NHSNumber;
-    /// let digits = [9, 9, 9, 1, 2 , 3, 4, 5, 6, 0];
-    /// let nhs_number = NHSNumber::new(digits);
+    /// use nhs_number::{CheckDigit, NHSNumber};
+    /// let digits = [9, 9, 9, 3, 4, 5, 6, 7, 8, 0];
+    /// let nhs_number = NHSNumber::try_new(digits).unwrap();
     /// let check_digit = nhs_number.calculate_check_digit();
-    /// assert_eq!(check_digit, 0);
+    /// assert_eq!(check_digit, CheckDigit::Digit(0));
     /// ```
     ///
     /// This method calls the function [calculate_check_digit()].
     ///
     #[allow(dead_code)]
-    pub fn calculate_check_digit(&self) -> i8 {
+    pub const fn calculate_check_digit(&self) -> CheckDigit {
         crate::calculate_check_digit(self.digits)
     }
 
@@ -176,8 +482,8 @@ impl NHSNumber {
     ///     
     /// ```rust
     /// use nhs_number::NHSNumber;
-    /// let digits = [9, 9, 9, 1, 2, 3, 4, 5, 6, 0];
-    /// let nhs_number = NHSNumber::new(digits);
+    /// let digits = [9, 9, 9, 3, 4, 5, 6, 7, 8, 0];
+    /// let nhs_number = NHSNumber::try_new(digits).unwrap();
     /// let is_valid = nhs_number.validate_check_digit();
     /// assert_eq!(is_valid, true);
     /// ```
@@ -185,10 +491,151 @@ impl NHSNumber {
     /// This method calls the function [validate_check_digit()].
     ///
     #[allow(dead_code)]
-    pub fn validate_check_digit(&self) -> bool {
+    pub const fn validate_check_digit(&self) -> bool {
         crate::validate_check_digit(self.digits)
     }
 
+    /// Classify the NHS Number into its issuing range, such as England, or
+    /// Scottish CHI, or the test range.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use nhs_number::{IssuingRange, NHSNumber};
+    /// let digits = [9, 9, 9, 3, 4, 5, 6, 7, 8, 0];
+    /// let nhs_number = NHSNumber::try_new(digits).unwrap();
+    /// assert_eq!(nhs_number.range(), IssuingRange::Test);
+    /// ```
+    ///
+    /// This method calls the function [issuing_range()].
+    ///
+    #[allow(dead_code)]
+    pub fn range(&self) -> IssuingRange {
+        crate::issuing_range(self.digits)
+    }
+
+    /// Validate the NHS Number fully, which combines the digit bounds, the
+    /// check digit, the repeated-digit numbers, and the issuing range.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use nhs_number::{IssuingRange, NHSNumber, ValidationError};
+    ///
+    /// let nhs_number = NHSNumber::try_new([9, 9, 9, 3, 4, 5, 6, 7, 8, 0]).unwrap();
+    /// assert_eq!(nhs_number.validate(), Ok(()));
+    ///
+    /// let nhs_number = NHSNumber::try_new([9, 9, 9, 9, 9, 9, 9, 9, 9, 9]).unwrap();
+    /// assert_eq!(nhs_number.validate(), Err(ValidationError::RepeatedDigits { digit: 9 }));
+    ///
+    /// let nhs_number = NHSNumber::try_new([9, 4, 3, 4, 7, 6, 5, 9, 1, 9]).unwrap();
+    /// assert_eq!(nhs_number.validate(), Err(ValidationError::OutOfRange { range: IssuingRange::Unallocated }));
+    /// ```
+    ///
+    /// This method calls the function [validate()].
+    ///
+    #[allow(dead_code)]
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        crate::validate(self.digits)
+    }
+
+    /// Suggest corrections for a mistyped NHS Number, such as two adjacent
+    /// digits swapped or one digit mistyped, ranked by likelihood.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use nhs_number::NHSNumber;
+    /// use nhs_number::correction::CorrectionKind;
+    ///
+    /// let corrections = NHSNumber::suggest_corrections("999 345 6870");
+    /// assert_eq!(corrections[0].nhs_number.to_string(), "999 345 6780");
+    /// assert_eq!(corrections[0].kind, CorrectionKind::Transposition { index: 7 });
+    /// ```
+    ///
+    /// This method calls the function [correction::suggest_corrections()]
+    /// with the default options.
+    ///
+    #[cfg(feature = "alloc")]
+    #[allow(dead_code)]
+    pub fn suggest_corrections(s: &str) -> Vec<Correction> {
+        crate::correction::suggest_corrections(s, CorrectionOptions::default())
+    }
+
+    /// Suggest corrections for a mistyped NHS Number, limited by the options.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use nhs_number::NHSNumber;
+    /// use nhs_number::correction::CorrectionOptions;
+    ///
+    /// let options = CorrectionOptions::new().limit(3).issuable_only(true);
+    /// let corrections = NHSNumber::suggest_corrections_with("401 023 2138", options);
+    /// assert!(corrections.len() <= 3);
+    /// assert!(corrections.iter().all(|c| c.nhs_number.range().is_issued()));
+    /// ```
+    ///
+    /// This method calls the function [correction::suggest_corrections()].
+    ///
+    #[cfg(feature = "alloc")]
+    #[allow(dead_code)]
+    pub fn suggest_corrections_with(s: &str, options: CorrectionOptions) -> Vec<Correction> {
+        crate::correction::suggest_corrections(s, options)
+    }
+
+    /// Get the next NHS Number with a valid check digit, in numeric order,
+    /// or `None` if there is no such number.
+    ///
+    /// This skips every number whose first nine digits yield the invalid
+    /// checksum 10.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use nhs_number::NHSNumber;
+    /// let nhs_number = NHSNumber::try_new([9, 9, 9, 1, 2, 3, 4, 5, 5, 1]).unwrap();
+    /// let successor = nhs_number.successor().unwrap();
+    /// assert_eq!(successor.to_string(), "999 123 4578");
+    /// ```
+    ///
+    #[allow(dead_code)]
+    pub fn successor(&self) -> Option<NHSNumber> {
+        let body = self.body();
+        if let Some(nhs_number) = NHSNumber::from_body_value(body)
+            && nhs_number > *self
+        {
+            return Some(nhs_number);
+        }
+        (body + 1..1_000_000_000).find_map(NHSNumber::from_body_value)
+    }
+
+    /// Get the previous NHS Number with a valid check digit, in numeric
+    /// order, or `None` if there is no such number.
+    ///
+    /// This skips every number whose first nine digits yield the invalid
+    /// checksum 10.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use nhs_number::NHSNumber;
+    /// let nhs_number = NHSNumber::try_new([9, 9, 9, 1, 2, 3, 4, 5, 7, 8]).unwrap();
+    /// let predecessor = nhs_number.predecessor().unwrap();
+    /// assert_eq!(predecessor.to_string(), "999 123 4551");
+    /// ```
+    ///
+    #[allow(dead_code)]
+    pub fn predecessor(&self) -> Option<NHSNumber> {
+        let body = self.body();
+        if let Some(nhs_number) = NHSNumber::from_body_value(body)
+            && nhs_number < *self
+        {
+            return Some(nhs_number);
+        }
+        (0..body).rev().find_map(NHSNumber::from_body_value)
+    }
+
     /// Generate a testable random sample NHS Number.
     ///
     /// Example:
@@ -202,10 +649,30 @@ impl NHSNumber {
     ///
     /// This method calls the function [testable_random_sample()].
     ///
+    #[cfg(all(feature = "testable", feature = "std"))]
     #[allow(dead_code)]
     pub fn testable_random_sample() -> NHSNumber {
         crate::testable_random_sample()
     }
+
+    /// Generate a testable random sample NHS Number with an invalid check
+    /// digit, which is for negative testing.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use nhs_number::NHSNumber;
+    /// let sample = NHSNumber::testable_random_invalid_sample();
+    /// assert!(!sample.validate_check_digit());
+    /// ```
+    ///
+    /// This method calls the function [testable_random_invalid_sample()].
+    ///
+    #[cfg(all(feature = "testable", feature = "std"))]
+    #[allow(dead_code)]
+    pub fn testable_random_invalid_sample() -> NHSNumber {
+        crate::testable_random_invalid_sample()
+    }
 }
 
 /// Format the NHS Number as a 10-digit number with spaces.
@@ -215,7 +682,7 @@ impl NHSNumber {
 /// ```rust
 /// use nhs_number::NHSNumber;
 /// let digits = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
-/// let nhs_number = NHSNumber::new(digits);
+/// let nhs_number = NHSNumber::try_new(digits).unwrap();
 /// let nhs_number_string = nhs_number.to_string();
 /// assert_eq!(nhs_number_string, "012 345 6789");
 /// ```
@@ -228,24 +695,89 @@ impl NHSNumber {
 /// * space
 /// * 4 digits
 ///
+/// The alternate flag `{:#}` formats without separators e.g. "0123456789",
+/// and the minus flag `{:-}` formats with hyphens e.g. "012-345-6789". For
+/// other styles, see [NHSNumber::display_with].
+///
 /// This method must be equivalent to the function [format()].
 ///
 impl fmt::Display for NHSNumber {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(
-            f,
-            "{}{}{} {}{}{} {}{}{}{}",
-            self.digits[0],
-            self.digits[1],
-            self.digits[2],
-            self.digits[3],
-            self.digits[4],
-            self.digits[5],
-            self.digits[6],
-            self.digits[7],
-            self.digits[8],
-            self.digits[9],
-        )
+        let style = if f.alternate() {
+            Style::Compact
+        } else if f.sign_minus() {
+            Style::Hyphenated
+        } else {
+            Style::Spaced
+        };
+        fmt::Display::fmt(&self.display_with(style), f)
+    }
+}
+
+/// Debug the NHS Number with all but the last four digits masked, so that a
+/// stray `{:?}` does not leak the full NHS Number.
+///
+/// Example:
+///
+/// ```rust
+/// use nhs_number::NHSNumber;
+/// let nhs_number = NHSNumber::try_new([9, 9, 9, 3, 4, 5, 6, 7, 8, 0]).unwrap();
+/// assert_eq!(format!("{:?}", nhs_number), "NHSNumber(*** *** 6780)");
+/// ```
+///
+/// To see every digit, call the method [NHSNumber::digits].
+///
+impl fmt::Debug for NHSNumber {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "NHSNumber({})", self.masked())
+    }
+}
+
+/// Convert digits into a NHS Number, if the digits are each in the range 0–9
+/// and the check digit is correct.
+///
+/// Example:
+///
+/// ```rust
+/// use nhs_number::NHSNumber;
+/// let digits: [i8; 10] = [9, 9, 9, 3, 4, 5, 6, 7, 8, 0];
+/// let nhs_number = NHSNumber::try_from(digits).unwrap();
+/// ```
+///
+/// This implementation calls the method [NHSNumber::try_new].
+///
+impl TryFrom<[i8; 10]> for NHSNumber {
+    type Error = ValidationError;
+    fn try_from(digits: [i8; 10]) -> Result<Self, Self::Error> {
+        NHSNumber::try_new(digits)
+    }
+}
+
+/// Convert digits into a NHS Number, if the digits are each in the range 0–9
+/// and the check digit is correct.
+///
+/// Example:
+///
+/// ```rust
+/// use nhs_number::{NHSNumber, ValidationError};
+/// let digits: [u8; 10] = [9, 9, 9, 3, 4, 5, 6, 7, 8, 0];
+/// let nhs_number = NHSNumber::try_from(digits).unwrap();
+///
+/// let digits: [u8; 10] = [9, 9, 9, 3, 4, 5, 6, 7, 8, 200];
+/// let result = NHSNumber::try_from(digits);
+/// assert_eq!(result, Err(ValidationError::InvalidDigit { index: 9, digit: 200 }));
+/// ```
+///
+impl TryFrom<[u8; 10]> for NHSNumber {
+    type Error = ValidationError;
+    fn try_from(digits: [u8; 10]) -> Result<Self, Self::Error> {
+        if let Some((index, &digit)) = digits.iter().enumerate().find(|(_, digit)| **digit > 9) {
+            return Err(ValidationError::InvalidDigit {
+                index,
+                digit: digit as i16,
+            });
+        }
+        NHSNumber::try_new(digits.map(|digit| digit as i8))
     }
 }
 
@@ -255,18 +787,19 @@ impl fmt::Display for NHSNumber {
 /// ```rust
 /// use nhs_number::NHSNumber;
 /// let digits = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
-/// let nhs_number = NHSNumber::new(digits);
+/// let nhs_number = NHSNumber::try_new(digits).unwrap();
 /// let nhs_number_string: String = nhs_number.into();
 /// assert_eq!(nhs_number_string, "012 345 6789");
 /// ```
 ///
-impl Into<String> for NHSNumber {
-    fn into(self) -> String {
-        self.to_string()
+#[cfg(feature = "alloc")]
+impl From<NHSNumber> for String {
+    fn from(nhs_number: NHSNumber) -> Self {
+        nhs_number.to_string()
     }
 }
 
-//// Functional utilities
+// Functional utilities
 
 /// Format the NHS Number as a 10-digit number with spaces.
 ///
@@ -289,21 +822,10 @@ impl Into<String> for NHSNumber {
 /// This function must be equivalent to the method
 /// [NHSNumber::Into](NHSNumber::into).
 ///
+#[cfg(feature = "alloc")]
 #[allow(dead_code)]
 pub fn format(digits: [i8; 10]) -> String {
-    format!(
-        "{}{}{} {}{}{} {}{}{}{}",
-        digits[0],
-        digits[1],
-        digits[2],
-        digits[3],
-        digits[4],
-        digits[5],
-        digits[6],
-        digits[7],
-        digits[8],
-        digits[9],
-    )
+    NHSNumber { digits }.display_with(Style::Spaced).to_string()
 }
 
 /// Get the NHS Number check digit i.e. the last digit.
@@ -319,7 +841,7 @@ pub fn format(digits: [i8; 10]) -> String {
 /// This function is called by the method [NHSNumber::check_digit](NHSNumber::check_digit).
 ///
 #[allow(dead_code)]
-pub fn check_digit(digits: [i8; 10]) -> i8 {
+pub const fn check_digit(digits: [i8; 10]) -> i8 {
     digits[9]
 }
 
@@ -328,22 +850,39 @@ pub fn check_digit(digits: [i8; 10]) -> i8 {
 /// Example:
 ///
 /// ```rust
+/// use nhs_number::CheckDigit;
+/// let digits = [9, 9, 9, 3, 4, 5, 6, 7, 8, 0];
+/// let check_digit = ::nhs_number::calculate_check_digit(digits);
+/// assert_eq!(check_digit, CheckDigit::Digit(0));
+/// ```
+///
+/// A checksum of 11 is represented by the check digit 0, and a checksum of 10
+/// means that no check digit can make the number valid:
+///
+/// ```rust
+/// use nhs_number::CheckDigit;
 /// let digits = [9, 9, 9, 1, 2, 3, 4, 5, 6, 0];
 /// let check_digit = ::nhs_number::calculate_check_digit(digits);
-/// assert_eq!(check_digit, 0);
+/// assert_eq!(check_digit, CheckDigit::Invalid);
 /// ```
 ///
+/// A digit of the first nine that is not in the range 0–9 also means that no
+/// check digit can make the number valid.
+///
 /// This function is called by the method [NHSNumber::calculate_check_digit](NHSNumber::calculate_check_digit).
 ///
 #[allow(dead_code)]
-pub fn calculate_check_digit(digits: [i8; 10]) -> i8 {
-    let sum: usize = digits
-        .iter()
-        .take(9)
-        .enumerate()
-        .map(|(i, &d)| d as usize * (10 - i as usize))
-        .sum();
-    ((11 - (sum % 11)) % 10) as i8
+pub const fn calculate_check_digit(digits: [i8; 10]) -> CheckDigit {
+    let mut sum: usize = 0;
+    let mut i = 0;
+    while i < 9 {
+        if digits[i] < 0 || digits[i] > 9 {
+            return CheckDigit::Invalid;
+        }
+        sum += digits[i] as usize * (10 - i);
+        i += 1;
+    }
+    CheckDigit::from_checksum(11 - (sum % 11))
 }
 
 /// Validate the NHS Number check digit equals the calculated check digit.
@@ -351,16 +890,55 @@ pub fn calculate_check_digit(digits: [i8; 10]) -> i8 {
 /// Example:
 ///     
 /// ```rust
-/// let digits = [9, 9, 9, 1, 2, 3, 4, 5, 6, 0];
+/// let digits = [9, 9, 9, 3, 4, 5, 6, 7, 8, 0];
 /// let is_valid = nhs_number::validate_check_digit(digits);
 /// assert_eq!(is_valid, true);
 /// ```
 ///
+/// A number whose first nine digits yield the invalid checksum 10 is never
+/// valid, whatever its check digit.
+///
 /// This function is called by the method [NHSNumber::validate_check_digit](NHSNumber::validate_check_digit).
 ///
 #[allow(dead_code)]
-pub fn validate_check_digit(digits: [i8; 10]) -> bool {
-    crate::check_digit(digits) == crate::calculate_check_digit(digits)
+pub const fn validate_check_digit(digits: [i8; 10]) -> bool {
+    match crate::calculate_check_digit(digits) {
+        CheckDigit::Digit(digit) => digit == crate::check_digit(digits),
+        CheckDigit::Invalid => false,
+    }
+}
+
+/// Validate the NHS Number fully, which combines these checks in order:
+///
+/// * Each digit is in the range 0–9.
+///
+/// * The check digit equals the calculated check digit.
+///
+/// * The digits are not all the same, such as 000 000 0000 or 444 444 4444,
+///   because such numbers are never issued even when the check digit is valid.
+///
+/// * The number is in a currently issued range, or in the test range.
+///
+/// Example:
+///
+/// ```rust
+/// let digits = [4, 0, 1, 0, 2, 3, 2, 1, 3, 7];
+/// assert_eq!(nhs_number::validate(digits), Ok(()));
+/// ```
+///
+/// This function is called by the method [NHSNumber::validate](NHSNumber::validate).
+///
+#[allow(dead_code)]
+pub fn validate(digits: [i8; 10]) -> Result<(), ValidationError> {
+    NHSNumber::try_new(digits)?;
+    if digits.iter().all(|&digit| digit == digits[0]) {
+        return Err(ValidationError::RepeatedDigits { digit: digits[0] });
+    }
+    let range = crate::issuing_range(digits);
+    if !range.is_issued() && range != IssuingRange::Test {
+        return Err(ValidationError::OutOfRange { range });
+    }
+    Ok(())
 }
 
 #[cfg(test)]
@@ -370,24 +948,188 @@ mod tests {
         use super::super::*;
 
         #[test]
-        fn test_new() {
-            let a: NHSNumber = NHSNumber::new([0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        fn test_new_unchecked() {
+            let a: NHSNumber = NHSNumber::new_unchecked([0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
             let actual = a.to_string();
             let expect = "012 345 6789";
             assert_eq!(actual, expect);
+            let a: NHSNumber = NHSNumber::new_unchecked([9, 9, 9, 3, 4, 5, 6, 7, 8, 1]);
+            assert!(!a.validate_check_digit());
+        }
+
+        #[test]
+        #[should_panic(expected = "range 0–9")]
+        fn test_new_unchecked_with_invalid_digit() {
+            NHSNumber::new_unchecked([-3, 42, 9, 3, 4, 5, 6, 7, 8, 0]);
+        }
+
+        #[test]
+        #[allow(deprecated)]
+        fn test_new() {
+            let a: NHSNumber = NHSNumber::new([9, 9, 9, 3, 4, 5, 6, 7, 8, 0]);
+            assert_eq!(a, NHSNumber::new_unchecked([9, 9, 9, 3, 4, 5, 6, 7, 8, 0]));
+        }
+
+        #[test]
+        fn test_try_new() {
+            {
+                let a = NHSNumber::try_new([9, 9, 9, 3, 4, 5, 6, 7, 8, 0]);
+                assert_eq!(
+                    a,
+                    Ok(NHSNumber::new_unchecked([9, 9, 9, 3, 4, 5, 6, 7, 8, 0]))
+                );
+            }
+            {
+                let a = NHSNumber::try_new([-3, 42, 9, 3, 4, 5, 6, 7, 8, 0]);
+                assert_eq!(
+                    a,
+                    Err(ValidationError::InvalidDigit {
+                        index: 0,
+                        digit: -3
+                    })
+                );
+            }
+            {
+                let a = NHSNumber::try_new([9, 9, 9, 3, 4, 5, 6, 7, 8, 1]);
+                assert_eq!(
+                    a,
+                    Err(ValidationError::InvalidCheckDigit {
+                        expected: CheckDigit::Digit(0),
+                        actual: 1
+                    })
+                );
+            }
+            {
+                let a = NHSNumber::try_new([9, 9, 9, 1, 2, 3, 4, 5, 6, 0]);
+                assert_eq!(
+                    a,
+                    Err(ValidationError::InvalidCheckDigit {
+                        expected: CheckDigit::Invalid,
+                        actual: 0
+                    })
+                );
+            }
+        }
+
+        #[test]
+        fn test_try_from_i8_array() {
+            let digits: [i8; 10] = [9, 4, 3, 4, 7, 6, 5, 9, 1, 9];
+            let a = NHSNumber::try_from(digits).unwrap();
+            assert_eq!(a.digits(), digits);
+        }
+
+        #[test]
+        fn test_try_from_u8_array() {
+            {
+                let digits: [u8; 10] = [9, 4, 3, 4, 7, 6, 5, 9, 1, 9];
+                let a = NHSNumber::try_from(digits).unwrap();
+                assert_eq!(a.digits(), [9, 4, 3, 4, 7, 6, 5, 9, 1, 9]);
+            }
+            {
+                let digits: [u8; 10] = [9, 4, 3, 4, 7, 6, 5, 9, 1, 255];
+                let a = NHSNumber::try_from(digits);
+                assert_eq!(
+                    a,
+                    Err(ValidationError::InvalidDigit {
+                        index: 9,
+                        digit: 255
+                    })
+                );
+            }
+        }
+
+        #[test]
+        fn test_from_body() {
+            let actual = NHSNumber::from_body([9, 4, 3, 4, 7, 6, 5, 9, 1]);
+            let expect = NHSNumber::new_unchecked([9, 4, 3, 4, 7, 6, 5, 9, 1, 9]);
+            assert_eq!(actual, Some(expect));
+            let actual = NHSNumber::from_body([9, 9, 9, 3, 4, 5, 6, 7, 8]);
+            let expect = NHSNumber::new_unchecked([9, 9, 9, 3, 4, 5, 6, 7, 8, 0]);
+            assert_eq!(actual, Some(expect));
+            assert_eq!(NHSNumber::from_body([9, 9, 9, 1, 2, 3, 4, 5, 6]), None);
+            assert_eq!(NHSNumber::from_body([9, 9, 9, 3, 4, 5, 6, 7, 10]), None);
+        }
+
+        #[test]
+        fn test_from_body_str() {
+            let actual = NHSNumber::from_body_str("943476591");
+            let expect = NHSNumber::new_unchecked([9, 4, 3, 4, 7, 6, 5, 9, 1, 9]);
+            assert_eq!(actual, Ok(expect));
+            assert_eq!(
+                NHSNumber::from_body_str("999123456"),
+                Err(ParseError::InvalidBody)
+            );
+            assert_eq!(
+                NHSNumber::from_body_str("9993456789"),
+                Err(ParseError::InvalidLength {
+                    expected: 9,
+                    actual: 10
+                })
+            );
+            assert_eq!(
+                NHSNumber::from_body_str("999 34567"),
+                Err(ParseError::InvalidCharacter {
+                    character: ' ',
+                    offset: 3
+                })
+            );
+        }
+
+        #[test]
+        fn test_digits() {
+            let a: NHSNumber = NHSNumber::new_unchecked([0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+            assert_eq!(a.digits(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
         }
 
         #[test]
         fn test_display() {
-            let a: NHSNumber = NHSNumber::new([0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+            let a: NHSNumber = NHSNumber::new_unchecked([0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
             let actual = a.to_string();
             let expect = "012 345 6789";
             assert_eq!(actual, expect);
         }
 
+        #[test]
+        fn test_display_with_flags() {
+            let a: NHSNumber = NHSNumber::new_unchecked([0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+            assert_eq!(format!("{}", a), "012 345 6789");
+            assert_eq!(format!("{:#}", a), "0123456789");
+            assert_eq!(format!("{:-}", a), "012-345-6789");
+            assert_eq!(format!("{:>14}", a), "  012 345 6789");
+        }
+
+        #[test]
+        fn test_display_with() {
+            let a: NHSNumber = NHSNumber::new_unchecked([0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+            assert_eq!(a.display_with(Style::Spaced).to_string(), "012 345 6789");
+            assert_eq!(a.display_with(Style::Compact).to_string(), "0123456789");
+            assert_eq!(
+                a.display_with(Style::Hyphenated).to_string(),
+                "012-345-6789"
+            );
+        }
+
+        #[test]
+        fn test_masked() {
+            let a: NHSNumber = NHSNumber::new_unchecked([0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+            assert_eq!(a.masked().to_string(), "*** *** 6789");
+            assert_eq!(
+                a.masked_with(MaskPolicy::leading(3)).to_string(),
+                "012 *** ****"
+            );
+        }
+
+        #[test]
+        fn test_debug() {
+            let a: NHSNumber = NHSNumber::new_unchecked([0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+            assert_eq!(format!("{:?}", a), "NHSNumber(*** *** 6789)");
+            assert_eq!(format!("{:?}", Some(a)), "Some(NHSNumber(*** *** 6789))");
+        }
+
+        #[cfg(feature = "alloc")]
         #[test]
         fn test_into_string() {
-            let a: NHSNumber = NHSNumber::new([0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+            let a: NHSNumber = NHSNumber::new_unchecked([0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
             let actual: String = a.into();
             let expect = "012 345 6789";
             assert_eq!(actual, expect);
@@ -396,20 +1138,20 @@ mod tests {
         #[test]
         fn test_partial_eq() {
             {
-                let a: NHSNumber = NHSNumber::new([0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
-                let b: NHSNumber = NHSNumber::new([0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+                let a: NHSNumber = NHSNumber::new_unchecked([0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+                let b: NHSNumber = NHSNumber::new_unchecked([0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
                 assert_eq!(a, b);
             }
             {
-                let a: NHSNumber = NHSNumber::new([0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
-                let b: NHSNumber = NHSNumber::new([9, 8, 7, 6, 5, 4, 3, 2, 1, 0]);
+                let a: NHSNumber = NHSNumber::new_unchecked([0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+                let b: NHSNumber = NHSNumber::new_unchecked([9, 8, 7, 6, 5, 4, 3, 2, 1, 0]);
                 assert_ne!(a, b);
             }
         }
 
         #[test]
         fn test_check_digit() {
-            let a = NHSNumber::new([0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+            let a = NHSNumber::new_unchecked([0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
             let actual: i8 = a.check_digit();
             let expect: i8 = 9;
             assert_eq!(actual, expect);
@@ -417,34 +1159,119 @@ mod tests {
 
         #[test]
         fn test_calculate_check_digit() {
-            let a: NHSNumber = NHSNumber::new([9, 9, 9, 1, 2, 3, 4, 5, 6, 0]);
-            let actual: i8 = a.calculate_check_digit();
-            let expect: i8 = 0;
+            let a: NHSNumber = NHSNumber::new_unchecked([9, 9, 9, 3, 4, 5, 6, 7, 8, 0]);
+            let actual: CheckDigit = a.calculate_check_digit();
+            let expect: CheckDigit = CheckDigit::Digit(0);
             assert_eq!(actual, expect);
         }
 
         #[test]
         fn test_validate_check_digit() {
             {
-                let a: NHSNumber = NHSNumber::new([9, 9, 9, 1, 2, 3, 4, 5, 6, 0]);
-                assert_eq!(a.validate_check_digit(), true);
+                let a: NHSNumber = NHSNumber::new_unchecked([9, 9, 9, 3, 4, 5, 6, 7, 8, 0]);
+                assert!(a.validate_check_digit());
             }
             {
-                let a: NHSNumber = NHSNumber::new([9, 9, 9, 1, 2, 3, 4, 5, 6, 1]);
-                assert_eq!(a.validate_check_digit(), false);
+                let a: NHSNumber = NHSNumber::new_unchecked([9, 9, 9, 3, 4, 5, 6, 7, 8, 1]);
+                assert!(!a.validate_check_digit());
             }
+            {
+                let a: NHSNumber = NHSNumber::new_unchecked([9, 9, 9, 1, 2, 3, 4, 5, 6, 0]);
+                assert!(!a.validate_check_digit());
+            }
+        }
+
+        #[test]
+        fn test_range() {
+            let a: NHSNumber = NHSNumber::new_unchecked([9, 4, 3, 4, 7, 6, 5, 9, 1, 9]);
+            assert_eq!(a.range(), IssuingRange::Unallocated);
+            let a: NHSNumber = NHSNumber::new_unchecked([4, 0, 1, 0, 2, 3, 2, 1, 3, 7]);
+            assert_eq!(a.range(), IssuingRange::EnglandWalesIsleOfMan);
+        }
+
+        #[test]
+        fn test_validate() {
+            let a: NHSNumber = NHSNumber::new_unchecked([4, 0, 1, 0, 2, 3, 2, 1, 3, 7]);
+            assert_eq!(a.validate(), Ok(()));
+            let a: NHSNumber = NHSNumber::new_unchecked([4, 0, 1, 0, 2, 3, 2, 1, 3, 8]);
+            assert_eq!(
+                a.validate(),
+                Err(ValidationError::InvalidCheckDigit {
+                    expected: CheckDigit::Digit(7),
+                    actual: 8
+                })
+            );
+        }
+
+        #[test]
+        fn test_successor() {
+            let a: NHSNumber = NHSNumber::new_unchecked([9, 9, 9, 1, 2, 3, 4, 5, 5, 1]);
+            let b: NHSNumber = NHSNumber::new_unchecked([9, 9, 9, 1, 2, 3, 4, 5, 7, 8]);
+            assert_eq!(a.successor(), Some(b));
+            let a: NHSNumber = NHSNumber::new_unchecked([9, 9, 9, 3, 4, 5, 6, 7, 7, 2]);
+            let b: NHSNumber = NHSNumber::new_unchecked([9, 9, 9, 3, 4, 5, 6, 7, 8, 0]);
+            assert_eq!(a.successor(), Some(b));
+            let a: NHSNumber = NHSNumber::new_unchecked([9, 9, 9, 3, 4, 5, 6, 7, 8, 0]);
+            let b: NHSNumber = NHSNumber::new_unchecked([9, 9, 9, 3, 4, 5, 6, 7, 9, 9]);
+            assert_eq!(a.successor(), Some(b));
+            let a: NHSNumber = NHSNumber::new_unchecked([9, 9, 9, 9, 9, 9, 9, 9, 9, 9]);
+            assert_eq!(a.successor(), None);
         }
 
+        #[test]
+        fn test_successor_with_invalid_check_digit() {
+            let a: NHSNumber = NHSNumber::new_unchecked([9, 9, 9, 3, 4, 5, 6, 7, 7, 0]);
+            let b: NHSNumber = NHSNumber::new_unchecked([9, 9, 9, 3, 4, 5, 6, 7, 7, 2]);
+            assert_eq!(a.successor(), Some(b));
+        }
+
+        #[test]
+        fn test_predecessor() {
+            let a: NHSNumber = NHSNumber::new_unchecked([9, 9, 9, 1, 2, 3, 4, 5, 7, 8]);
+            let b: NHSNumber = NHSNumber::new_unchecked([9, 9, 9, 1, 2, 3, 4, 5, 5, 1]);
+            assert_eq!(a.predecessor(), Some(b));
+            let a: NHSNumber = NHSNumber::new_unchecked([9, 9, 9, 3, 4, 5, 6, 7, 8, 5]);
+            let b: NHSNumber = NHSNumber::new_unchecked([9, 9, 9, 3, 4, 5, 6, 7, 8, 0]);
+            assert_eq!(a.predecessor(), Some(b));
+            assert_eq!(
+                NHSNumber::new_unchecked([0, 0, 0, 0, 0, 0, 0, 0, 0, 0]).predecessor(),
+                None
+            );
+        }
+
+        #[test]
+        fn test_successor_and_predecessor_are_inverse() {
+            let mut a: NHSNumber = NHSNumber::new_unchecked([9, 9, 9, 3, 4, 5, 6, 7, 8, 0]);
+            for _ in 0..100 {
+                let b = a.successor().unwrap();
+                assert_eq!(b.predecessor(), Some(a));
+                a = b;
+            }
+        }
+
+        #[cfg(all(feature = "testable", feature = "std"))]
         #[test]
         fn test_testable_random_sample() {
             let a: NHSNumber = NHSNumber::testable_random_sample();
             assert!(a >= *crate::testable::TESTABLE_MIN);
             assert!(a <= *crate::testable::TESTABLE_MAX);
+            assert!(a.validate_check_digit());
+        }
+
+        #[cfg(all(feature = "testable", feature = "std"))]
+        #[test]
+        fn test_testable_random_invalid_sample() {
+            let a: NHSNumber = NHSNumber::testable_random_invalid_sample();
+            assert!(a >= *crate::testable::TESTABLE_MIN);
+            assert!(a <= *crate::testable::TESTABLE_MAX);
+            assert!(!a.validate_check_digit());
         }
     }
 
     mod utilities {
+        use crate::{CheckDigit, IssuingRange, ValidationError};
 
+        #[cfg(feature = "alloc")]
         #[test]
         fn test_format() {
             let digits = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
@@ -463,10 +1290,97 @@ mod tests {
 
         #[test]
         fn test_calculate_check_digit() {
+            let digits = [9, 9, 9, 3, 4, 5, 6, 7, 8, 0];
+            let actual: CheckDigit = crate::calculate_check_digit(digits);
+            let expect: CheckDigit = CheckDigit::Digit(0);
+            assert_eq!(actual, expect);
+        }
+
+        #[test]
+        fn test_calculate_check_digit_with_checksum_11() {
+            let digits = [9, 8, 7, 6, 5, 4, 3, 2, 1, 0];
+            let actual: CheckDigit = crate::calculate_check_digit(digits);
+            let expect: CheckDigit = CheckDigit::Digit(0);
+            assert_eq!(actual, expect);
+        }
+
+        #[test]
+        fn test_calculate_check_digit_with_checksum_10() {
             let digits = [9, 9, 9, 1, 2, 3, 4, 5, 6, 0];
-            let actual: i8 = crate::calculate_check_digit(digits);
-            let expect: i8 = 0;
+            let actual: CheckDigit = crate::calculate_check_digit(digits);
+            let expect: CheckDigit = CheckDigit::Invalid;
             assert_eq!(actual, expect);
         }
+
+        #[test]
+        fn test_calculate_check_digit_with_out_of_range_digit() {
+            let actual: CheckDigit = crate::calculate_check_digit([-1; 10]);
+            assert_eq!(actual, CheckDigit::Invalid);
+            let actual: CheckDigit = crate::calculate_check_digit([i8::MAX; 10]);
+            assert_eq!(actual, CheckDigit::Invalid);
+        }
+
+        #[test]
+        fn test_validate() {
+            assert_eq!(crate::validate([9, 9, 9, 3, 4, 5, 6, 7, 8, 0]), Ok(()));
+            assert_eq!(crate::validate([4, 0, 1, 0, 2, 3, 2, 1, 3, 7]), Ok(()));
+            assert_eq!(
+                crate::validate([4, 0, 1, 0, 2, 3, 2, 1, 3, 10]),
+                Err(ValidationError::InvalidDigit {
+                    index: 9,
+                    digit: 10
+                })
+            );
+            assert_eq!(
+                crate::validate([9, 9, 9, 3, 4, 5, 6, 7, 8, 1]),
+                Err(ValidationError::InvalidCheckDigit {
+                    expected: CheckDigit::Digit(0),
+                    actual: 1
+                })
+            );
+            assert_eq!(
+                crate::validate([0, 0, 0, 0, 0, 0, 0, 0, 0, 0]),
+                Err(ValidationError::RepeatedDigits { digit: 0 })
+            );
+            assert_eq!(
+                crate::validate([1, 1, 1, 1, 1, 1, 1, 1, 1, 1]),
+                Err(ValidationError::RepeatedDigits { digit: 1 })
+            );
+            assert_eq!(
+                crate::validate([4, 4, 4, 4, 4, 4, 4, 4, 4, 4]),
+                Err(ValidationError::RepeatedDigits { digit: 4 })
+            );
+            assert_eq!(
+                crate::validate([0, 1, 2, 3, 4, 5, 6, 7, 8, 9]),
+                Err(ValidationError::OutOfRange {
+                    range: IssuingRange::ScottishChi
+                })
+            );
+            assert_eq!(
+                crate::validate([9, 4, 3, 4, 7, 6, 5, 9, 1, 9]),
+                Err(ValidationError::OutOfRange {
+                    range: IssuingRange::Unallocated
+                })
+            );
+            assert_eq!(
+                crate::validate([3, 9, 9, 9, 9, 9, 9, 9, 9, 3]),
+                Err(ValidationError::OutOfRange {
+                    range: IssuingRange::NorthernIrelandHAndC
+                })
+            );
+        }
+
+        #[test]
+        fn test_validate_check_digit() {
+            assert!(crate::validate_check_digit([9, 4, 3, 4, 7, 6, 5, 9, 1, 9]));
+            assert!(crate::validate_check_digit([9, 8, 7, 6, 5, 4, 3, 2, 1, 0]));
+            assert!(!crate::validate_check_digit([9, 8, 7, 6, 5, 4, 3, 2, 1, 1]));
+            assert!(!crate::validate_check_digit([9, 9, 9, 1, 2, 3, 4, 5, 6, 0]));
+            assert!(!crate::validate_check_digit([-1; 10]));
+            assert!(!crate::validate_check_digit([i8::MIN; 10]));
+            assert!(!crate::validate_check_digit([
+                9, 9, 9, 3, 4, 5, 6, 7, 8, -11
+            ]));
+        }
     }
 }
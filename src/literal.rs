@@ -0,0 +1,153 @@
+//! Compile-time NHS Number literals, via the macro [nhs_number!](crate::nhs_number).
+//!
+//! The macro parses and validates a string literal at compile time, so a
+//! fixture table of known test patients needs no parsing at startup, and a
+//! malformed literal or a wrong check digit fails the build.
+//!
+//! Example:
+//!
+//! ```rust
+//! use nhs_number::{NHSNumber, nhs_number};
+//!
+//! const PATIENTS: [NHSNumber; 2] = [
+//!     nhs_number!("999 345 6780"),
+//!     nhs_number!("9434765919"),
+//! ];
+//! assert_eq!(PATIENTS[0].to_string(), "999 345 6780");
+//! ```
+//!
+//! A literal with a wrong check digit fails the build:
+//!
+//! ```compile_fail
+//! use nhs_number::nhs_number;
+//! let nhs_number = nhs_number!("999 345 6781");
+//! ```
+
+use crate::NHSNumber;
+
+/// Create a NHS Number from a string literal, validated at compile time.
+///
+/// The literal uses the same format as `FromStr`: either 10 digits, or 12
+/// characters in '3 3 4' format with space separators. A malformed literal or
+/// a wrong check digit fails the build.
+///
+/// Example:
+///
+/// ```rust
+/// use nhs_number::{NHSNumber, nhs_number};
+/// let nhs_number = nhs_number!("999 345 6780");
+/// assert_eq!(nhs_number, NHSNumber::try_new([9, 9, 9, 3, 4, 5, 6, 7, 8, 0]).unwrap());
+/// ```
+///
+/// A malformed literal fails the build:
+///
+/// ```compile_fail
+/// use nhs_number::nhs_number;
+/// let nhs_number = nhs_number!("999-345-6780");
+/// ```
+///
+/// This macro calls the function [literal::parse()](crate::literal::parse)
+/// in a const item.
+///
+#[macro_export]
+macro_rules! nhs_number {
+    ($s:literal) => {{
+        const NHS_NUMBER: $crate::NHSNumber = $crate::literal::parse($s);
+        NHS_NUMBER
+    }};
+}
+
+/// Parse a string into a NHS Number in a const context, or panic.
+///
+/// In a const item, the panic is a compile error, which is how the macro
+/// [nhs_number!](crate::nhs_number) fails the build. At runtime, prefer the
+/// `FromStr` implementation, which returns an error instead.
+///
+/// Example:
+///
+/// ```rust
+/// use nhs_number::{NHSNumber, literal};
+/// const NHS_NUMBER: NHSNumber = literal::parse("999 345 6780");
+/// assert_eq!(NHS_NUMBER.digits(), [9, 9, 9, 3, 4, 5, 6, 7, 8, 0]);
+/// ```
+///
+/// Panics if the string is not 10 digits or 12 characters in '3 3 4' format,
+/// or if the check digit is wrong.
+///
+pub const fn parse(s: &str) -> NHSNumber {
+    let bytes = s.as_bytes();
+    let grouped = match bytes.len() {
+        10 => false,
+        12 => true,
+        _ => panic!("NHS Number literal must be 10 digits, or 12 characters in '3 3 4' format"),
+    };
+    let mut digits: [i8; 10] = [0; 10];
+    let mut count = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        let byte = bytes[i];
+        if grouped && (i == 3 || i == 7) {
+            if byte != b' ' {
+                panic!("NHS Number literal has a misplaced separator");
+            }
+        } else if byte.is_ascii_digit() {
+            digits[count] = (byte - b'0') as i8;
+            count += 1;
+        } else {
+            panic!("NHS Number literal has an invalid character");
+        }
+        i += 1;
+    }
+    match NHSNumber::try_new(digits) {
+        Ok(nhs_number) => nhs_number,
+        Err(_) => panic!("NHS Number literal has an invalid check digit"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_macro() {
+        const NHS_NUMBER: NHSNumber = crate::nhs_number!("999 345 6780");
+        assert_eq!(NHS_NUMBER, NHSNumber::from_str("999 345 6780").unwrap());
+        assert_eq!(
+            crate::nhs_number!("9434765919"),
+            NHSNumber::from_str("943 476 5919").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse("012 345 6789"),
+            NHSNumber::new_unchecked([0, 1, 2, 3, 4, 5, 6, 7, 8, 9])
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid check digit")]
+    fn test_parse_with_invalid_check_digit() {
+        parse("999 345 6781");
+    }
+
+    #[test]
+    #[should_panic(expected = "misplaced separator")]
+    fn test_parse_with_misplaced_separator() {
+        parse("999-345-6780");
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid character")]
+    fn test_parse_with_invalid_character() {
+        parse("999345678X");
+    }
+
+    #[test]
+    #[should_panic(expected = "must be 10 digits")]
+    fn test_parse_with_invalid_length() {
+        parse("999 345 678");
+    }
+}
@@ -0,0 +1,184 @@
+use crate::NHSNumber;
+use crate::style::{Style, fmt_digits};
+use core::fmt;
+
+/// NHS Number mask policy, which chooses which digits to show, and how to
+/// display the hidden digits.
+///
+/// The default policy shows the last four digits, hides the other digits with
+/// '*', and uses the spaced style e.g. "*** *** 6780".
+///
+/// Example:
+///
+/// ```rust
+/// use nhs_number::{NHSNumber, mask::MaskPolicy, style::Style};
+/// let nhs_number = NHSNumber::try_new([9, 9, 9, 3, 4, 5, 6, 7, 8, 0]).unwrap();
+///
+/// assert_eq!(nhs_number.masked().to_string(), "*** *** 6780");
+///
+/// let policy = MaskPolicy::leading(3);
+/// assert_eq!(nhs_number.masked_with(policy).to_string(), "999 *** ****");
+///
+/// let policy = MaskPolicy::trailing(2).mask('#').style(Style::Hyphenated);
+/// assert_eq!(nhs_number.masked_with(policy).to_string(), "###-###-##80");
+/// ```
+///
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct MaskPolicy {
+    visible: Visible,
+    mask: char,
+    style: Style,
+}
+
+/// Which digits a mask policy shows.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Visible {
+    /// Show this count of leading digits, and hide the rest.
+    Leading(usize),
+
+    /// Show this count of trailing digits, and hide the rest.
+    Trailing(usize),
+}
+
+impl MaskPolicy {
+    /// Create a mask policy that shows this count of leading digits.
+    pub fn leading(count: usize) -> Self {
+        MaskPolicy {
+            visible: Visible::Leading(count),
+            ..MaskPolicy::default()
+        }
+    }
+
+    /// Create a mask policy that shows this count of trailing digits.
+    pub fn trailing(count: usize) -> Self {
+        MaskPolicy {
+            visible: Visible::Trailing(count),
+            ..MaskPolicy::default()
+        }
+    }
+
+    /// Create a mask policy that hides every digit.
+    pub fn all() -> Self {
+        MaskPolicy::leading(0)
+    }
+
+    /// Set the character that replaces each hidden digit.
+    pub fn mask(mut self, mask: char) -> Self {
+        self.mask = mask;
+        self
+    }
+
+    /// Set the display style, such as spaced or compact.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Is the digit at this index visible?
+    pub fn is_visible(&self, index: usize) -> bool {
+        match self.visible {
+            Visible::Leading(count) => index < count,
+            Visible::Trailing(count) => index >= 10usize.saturating_sub(count),
+        }
+    }
+}
+
+/// The default mask policy shows the last four digits e.g. "*** *** 6780".
+impl Default for MaskPolicy {
+    fn default() -> Self {
+        MaskPolicy {
+            visible: Visible::Trailing(4),
+            mask: '*',
+            style: Style::Spaced,
+        }
+    }
+}
+
+/// NHS Number with a mask policy, which implements `Display` without
+/// allocating, and without showing the hidden digits.
+///
+/// Create this by calling the method [NHSNumber::masked] or the method
+/// [NHSNumber::masked_with].
+///
+#[derive(PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Masked {
+    nhs_number: NHSNumber,
+    policy: MaskPolicy,
+}
+
+impl Masked {
+    /// Create a NHS Number with a mask policy.
+    pub fn new(nhs_number: NHSNumber, policy: MaskPolicy) -> Self {
+        Masked { nhs_number, policy }
+    }
+}
+
+impl fmt::Display for Masked {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_digits(f, self.nhs_number.digits(), self.policy.style, |i| {
+            (!self.policy.is_visible(i)).then_some(self.policy.mask)
+        })
+    }
+}
+
+impl fmt::Debug for Masked {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Masked({})", self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn nhs_number() -> NHSNumber {
+        NHSNumber::new_unchecked([9, 9, 9, 3, 4, 5, 6, 7, 8, 0])
+    }
+
+    #[test]
+    fn test_default() {
+        let masked = Masked::new(nhs_number(), MaskPolicy::default());
+        assert_eq!(masked.to_string(), "*** *** 6780");
+    }
+
+    #[test]
+    fn test_leading() {
+        let masked = Masked::new(nhs_number(), MaskPolicy::leading(3));
+        assert_eq!(masked.to_string(), "999 *** ****");
+    }
+
+    #[test]
+    fn test_trailing() {
+        let masked = Masked::new(nhs_number(), MaskPolicy::trailing(1));
+        assert_eq!(masked.to_string(), "*** *** ***0");
+    }
+
+    #[test]
+    fn test_all() {
+        let masked = Masked::new(nhs_number(), MaskPolicy::all());
+        assert_eq!(masked.to_string(), "*** *** ****");
+    }
+
+    #[test]
+    fn test_count_more_than_digits() {
+        let masked = Masked::new(nhs_number(), MaskPolicy::trailing(20));
+        assert_eq!(masked.to_string(), "999 345 6780");
+        let masked = Masked::new(nhs_number(), MaskPolicy::trailing(usize::MAX));
+        assert_eq!(masked.to_string(), "999 345 6780");
+        let masked = Masked::new(nhs_number(), MaskPolicy::leading(usize::MAX));
+        assert_eq!(masked.to_string(), "999 345 6780");
+    }
+
+    #[test]
+    fn test_mask_with_multibyte_character() {
+        let policy = MaskPolicy::trailing(4).mask('•').style(Style::Compact);
+        let masked = Masked::new(nhs_number(), policy);
+        assert_eq!(masked.to_string(), "••••••6780");
+    }
+
+    #[test]
+    fn test_debug() {
+        let masked = Masked::new(nhs_number(), MaskPolicy::default());
+        assert_eq!(format!("{:?}", masked), "Masked(*** *** 6780)");
+    }
+}
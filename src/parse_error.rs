@@ -1,3 +1,156 @@
+use crate::CheckDigit;
+use core::fmt;
+
 /// NHS Number Parse Error, which is for the implementation `FromStr`.
-#[derive(Debug, PartialEq, Eq)]
-pub struct ParseError;
+///
+/// Each variant describes why the input was rejected, so that callers can
+/// explain the problem to the person who typed it.
+///
+/// Offsets are byte offsets into the input string.
+///
+/// Example:
+///
+/// ```rust
+/// use nhs_number::{NHSNumber, parse_error::ParseError};
+/// use std::str::FromStr;
+/// let result = NHSNumber::from_str("999 3X5 6780");
+/// assert_eq!(result, Err(ParseError::InvalidCharacter { character: 'X', offset: 5 }));
+/// ```
+///
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ParseError {
+    /// The input has the wrong number of characters.
+    InvalidLength { expected: usize, actual: usize },
+
+    /// The input has the wrong number of digits, when parsing with lenient
+    /// separators, which ignores the separators.
+    InvalidDigitCount { expected: usize, actual: usize },
+
+    /// The input has a character that is neither a digit nor a separator.
+    InvalidCharacter { character: char, offset: usize },
+
+    /// The input has a separator where a digit is expected, or has some other
+    /// character where a separator is expected.
+    MisplacedSeparator { character: char, offset: usize },
+
+    /// The input check digit differs from the calculated check digit.
+    InvalidCheckDigit { expected: CheckDigit, actual: i8 },
+
+    /// The input is a body i.e. the first nine digits, which yields the
+    /// invalid checksum 10, so no check digit can complete it.
+    InvalidBody,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::InvalidLength { expected, actual } => {
+                write!(
+                    f,
+                    "invalid length: expected {} characters, found {}",
+                    expected, actual
+                )
+            }
+            ParseError::InvalidDigitCount { expected, actual } => {
+                write!(
+                    f,
+                    "invalid digit count: expected {} digits, found {}",
+                    expected, actual
+                )
+            }
+            ParseError::InvalidCharacter { character, offset } => {
+                write!(f, "invalid character {:?} at offset {}", character, offset)
+            }
+            ParseError::MisplacedSeparator { character, offset } => {
+                write!(
+                    f,
+                    "misplaced separator {:?} at offset {}",
+                    character, offset
+                )
+            }
+            ParseError::InvalidCheckDigit { expected, actual } => match expected {
+                CheckDigit::Digit(expected) => write!(
+                    f,
+                    "invalid check digit: expected {}, found {}",
+                    expected, actual
+                ),
+                CheckDigit::Invalid => write!(
+                    f,
+                    "invalid check digit: the first nine digits can never have a valid check digit, found {}",
+                    actual
+                ),
+            },
+            ParseError::InvalidBody => write!(
+                f,
+                "invalid body: the nine digits can never have a valid check digit"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            ParseError::InvalidLength {
+                expected: 10,
+                actual: 3
+            }
+            .to_string(),
+            "invalid length: expected 10 characters, found 3"
+        );
+        assert_eq!(
+            ParseError::InvalidDigitCount {
+                expected: 10,
+                actual: 11
+            }
+            .to_string(),
+            "invalid digit count: expected 10 digits, found 11"
+        );
+        assert_eq!(
+            ParseError::InvalidCharacter {
+                character: 'X',
+                offset: 5
+            }
+            .to_string(),
+            "invalid character 'X' at offset 5"
+        );
+        assert_eq!(
+            ParseError::MisplacedSeparator {
+                character: '-',
+                offset: 3
+            }
+            .to_string(),
+            "misplaced separator '-' at offset 3"
+        );
+        assert_eq!(
+            ParseError::InvalidCheckDigit {
+                expected: CheckDigit::Digit(0),
+                actual: 1
+            }
+            .to_string(),
+            "invalid check digit: expected 0, found 1"
+        );
+        assert_eq!(
+            ParseError::InvalidBody.to_string(),
+            "invalid body: the nine digits can never have a valid check digit"
+        );
+    }
+
+    #[test]
+    fn test_error() {
+        let error: Box<dyn std::error::Error> = Box::new(ParseError::InvalidLength {
+            expected: 10,
+            actual: 3,
+        });
+        assert_eq!(
+            error.to_string(),
+            "invalid length: expected 10 characters, found 3"
+        );
+    }
+}
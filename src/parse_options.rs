@@ -0,0 +1,451 @@
+use crate::parse_error::ParseError;
+use crate::{CheckDigit, NHSNumber};
+
+/// Separators that the strict parser allows, which is a space.
+pub const STRICT_SEPARATORS: &[char] = &[' '];
+
+/// Separators that the lenient parser allows, which includes spaces,
+/// non-breaking spaces, hyphens, and dots.
+pub const LENIENT_SEPARATORS: &[char] = &[
+    ' ', '\u{00A0}', '\u{2007}', '\u{202F}', '-', '\u{2010}', '\u{2011}', '.',
+];
+
+/// Prefixes that the lenient parser removes, which are common labels.
+///
+/// The comparison ignores ASCII case, and the first matching prefix wins,
+/// so longer prefixes come before their shorter beginnings.
+pub const LENIENT_PREFIXES: &[&str] = &[
+    "NHS Number:",
+    "NHS Number",
+    "NHS No.:",
+    "NHS No:",
+    "NHS No.",
+    "NHS No",
+    "NHS#:",
+    "NHS#",
+    "NHS:",
+    "NHS",
+];
+
+/// The zero character of each Unicode decimal digit block that the parser can
+/// normalise; each block is ten consecutive characters from zero to nine.
+const UNICODE_ZEROS: &[char] = &[
+    '\u{0660}', // Arabic-Indic
+    '\u{06F0}', // Extended Arabic-Indic
+    '\u{0966}', // Devanagari
+    '\u{09E6}', // Bengali
+    '\u{FF10}', // Full-width
+];
+
+/// NHS Number parse options, which configure how to parse a string.
+///
+/// The strict options are equivalent to the `FromStr` implementation: the
+/// input must be exactly 10 digits, or 12 characters in '3 3 4' format with
+/// space separators.
+///
+/// The lenient options are for messy real-world input, such as spreadsheets:
+///
+/// * Trim leading and trailing whitespace, including non-breaking spaces.
+///
+/// * Remove a label prefix such as "NHS No: ".
+///
+/// * Allow separators such as spaces, hyphens, and dots between any digits.
+///
+/// * Normalise Unicode digits such as full-width digits.
+///
+/// Every option parser verifies the check digit.
+///
+/// Example:
+///
+/// ```rust
+/// use nhs_number::{NHSNumber, parse_options::ParseOptions};
+///
+/// let nhs_number = ParseOptions::lenient().parse(" NHS No: 999-345-6780 ").unwrap();
+/// assert_eq!(nhs_number, NHSNumber::try_new([9, 9, 9, 3, 4, 5, 6, 7, 8, 0]).unwrap());
+///
+/// let result = ParseOptions::strict().parse(" NHS No: 999-345-6780 ");
+/// assert!(result.is_err());
+/// ```
+///
+/// Example with custom options:
+///
+/// ```rust
+/// use nhs_number::parse_options::ParseOptions;
+///
+/// let options = ParseOptions::strict().separators(&[' ', '-']).trim(true);
+/// assert!(options.parse(" 999-345-6780 ").is_ok());
+/// assert!(options.parse(" 9993-456780 ").is_err());
+/// ```
+///
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ParseOptions<'a> {
+    lenient: bool,
+    separators: &'a [char],
+    prefixes: &'a [&'a str],
+    trim: bool,
+    normalize_digits: bool,
+}
+
+impl<'a> ParseOptions<'a> {
+    /// Create strict parse options, which are equivalent to `FromStr`.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use nhs_number::parse_options::ParseOptions;
+    /// let options = ParseOptions::strict();
+    /// assert!(options.parse("999 345 6780").is_ok());
+    /// assert!(options.parse("999-345-6780").is_err());
+    /// ```
+    ///
+    pub fn strict() -> Self {
+        ParseOptions {
+            lenient: false,
+            separators: STRICT_SEPARATORS,
+            prefixes: &[],
+            trim: false,
+            normalize_digits: false,
+        }
+    }
+
+    /// Create lenient parse options, which are for messy real-world input.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use nhs_number::parse_options::ParseOptions;
+    /// let options = ParseOptions::lenient();
+    /// assert!(options.parse("999.345.6780").is_ok());
+    /// assert!(options.parse("\u{00A0}999 345 6780\u{00A0}").is_ok());
+    /// assert!(options.parse("nhs no: ９９９３４５６７８０").is_ok());
+    /// ```
+    ///
+    pub fn lenient() -> Self {
+        ParseOptions {
+            lenient: true,
+            separators: LENIENT_SEPARATORS,
+            prefixes: LENIENT_PREFIXES,
+            trim: true,
+            normalize_digits: true,
+        }
+    }
+
+    /// Set lenient separators: when true, separators may be anywhere between
+    /// the digits; when false, separators must be in '3 3 4' format.
+    pub fn lenient_separators(mut self, lenient: bool) -> Self {
+        self.lenient = lenient;
+        self
+    }
+
+    /// Set the allowed separator characters.
+    pub fn separators(mut self, separators: &'a [char]) -> Self {
+        self.separators = separators;
+        self
+    }
+
+    /// Set the label prefixes to remove, such as "NHS No:".
+    pub fn prefixes(mut self, prefixes: &'a [&'a str]) -> Self {
+        self.prefixes = prefixes;
+        self
+    }
+
+    /// Set whether to trim leading and trailing whitespace.
+    pub fn trim(mut self, trim: bool) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    /// Set whether to normalise Unicode digits, such as full-width digits.
+    pub fn normalize_digits(mut self, normalize_digits: bool) -> Self {
+        self.normalize_digits = normalize_digits;
+        self
+    }
+
+    /// Parse a string into a NHS Number by using these options.
+    ///
+    /// Error offsets are byte offsets into the original string.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use nhs_number::{parse_error::ParseError, parse_options::ParseOptions};
+    /// let result = ParseOptions::lenient().parse("NHS No: 999 345 67B0");
+    /// assert_eq!(result, Err(ParseError::InvalidCharacter { character: 'B', offset: 18 }));
+    /// ```
+    ///
+    pub fn parse(&self, s: &str) -> Result<NHSNumber, ParseError> {
+        let (offset, s) = self.strip(s);
+        let digits = if self.lenient {
+            self.parse_lenient(s, offset)?
+        } else {
+            self.parse_grouped(s, offset)?
+        };
+        let expected = crate::calculate_check_digit(digits);
+        if expected != CheckDigit::Digit(digits[9]) {
+            return Err(ParseError::InvalidCheckDigit {
+                expected,
+                actual: digits[9],
+            });
+        }
+        Ok(NHSNumber { digits })
+    }
+
+    /// Remove whitespace and prefixes, and return the offset of the remainder.
+    fn strip<'s>(&self, s: &'s str) -> (usize, &'s str) {
+        let mut offset = 0;
+        let mut rest = s;
+        if self.trim {
+            let trimmed = rest.trim_start();
+            offset += rest.len() - trimmed.len();
+            rest = trimmed.trim_end();
+        }
+        if let Some(prefix) = self.prefixes.iter().find(|prefix| {
+            rest.get(..prefix.len())
+                .is_some_and(|head| head.eq_ignore_ascii_case(prefix))
+        }) {
+            offset += prefix.len();
+            rest = &rest[prefix.len()..];
+            if self.trim {
+                let trimmed = rest.trim_start();
+                offset += rest.len() - trimmed.len();
+                rest = trimmed;
+            }
+        }
+        (offset, rest)
+    }
+
+    /// Parse either 10 digits, or 12 characters in '3 3 4' format.
+    fn parse_grouped(&self, s: &str, offset: usize) -> Result<[i8; 10], ParseError> {
+        let length = s.chars().count();
+        let grouped = match length {
+            10 => false,
+            12 => true,
+            _ => {
+                return Err(ParseError::InvalidLength {
+                    expected: if s.contains(self.separators) { 12 } else { 10 },
+                    actual: length,
+                });
+            }
+        };
+        let mut digits: [i8; 10] = [0; 10];
+        let mut count = 0;
+        let mut separator = None;
+        for (i, (index, character)) in s.char_indices().enumerate() {
+            let offset = offset + index;
+            if grouped && (i == 3 || i == 7) {
+                if !self.separators.contains(&character)
+                    || separator.is_some_and(|separator| separator != character)
+                {
+                    return Err(ParseError::MisplacedSeparator { character, offset });
+                }
+                separator = Some(character);
+                continue;
+            }
+            match self.digit(character) {
+                Some(digit) => {
+                    digits[count] = digit;
+                    count += 1;
+                }
+                None if self.separators.contains(&character) => {
+                    return Err(ParseError::MisplacedSeparator { character, offset });
+                }
+                None => {
+                    return Err(ParseError::InvalidCharacter { character, offset });
+                }
+            }
+        }
+        Ok(digits)
+    }
+
+    /// Parse 10 digits with any number of separators anywhere.
+    fn parse_lenient(&self, s: &str, offset: usize) -> Result<[i8; 10], ParseError> {
+        let mut digits: [i8; 10] = [0; 10];
+        let mut count = 0;
+        for (index, character) in s.char_indices() {
+            match self.digit(character) {
+                Some(digit) => {
+                    if count < 10 {
+                        digits[count] = digit;
+                    }
+                    count += 1;
+                }
+                None if self.separators.contains(&character) => {}
+                None => {
+                    return Err(ParseError::InvalidCharacter {
+                        character,
+                        offset: offset + index,
+                    });
+                }
+            }
+        }
+        if count != 10 {
+            return Err(ParseError::InvalidDigitCount {
+                expected: 10,
+                actual: count,
+            });
+        }
+        Ok(digits)
+    }
+
+    /// Convert a character to a digit, optionally normalising Unicode digits.
+    fn digit(&self, character: char) -> Option<i8> {
+        if let Some(digit) = character.to_digit(10) {
+            return Some(digit as i8);
+        }
+        if self.normalize_digits {
+            for zero in UNICODE_ZEROS {
+                let digit = (character as u32).wrapping_sub(*zero as u32);
+                if digit <= 9 {
+                    return Some(digit as i8);
+                }
+            }
+        }
+        None
+    }
+}
+
+/// The default parse options are strict, which are equivalent to `FromStr`.
+impl Default for ParseOptions<'_> {
+    fn default() -> Self {
+        ParseOptions::strict()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expect() -> NHSNumber {
+        NHSNumber::new_unchecked([9, 9, 9, 3, 4, 5, 6, 7, 8, 0])
+    }
+
+    #[test]
+    fn test_strict() {
+        let options = ParseOptions::strict();
+        assert_eq!(options.parse("9993456780"), Ok(expect()));
+        assert_eq!(options.parse("999 345 6780"), Ok(expect()));
+        assert_eq!(
+            options.parse("999-345-6780"),
+            Err(ParseError::MisplacedSeparator {
+                character: '-',
+                offset: 3
+            })
+        );
+        assert_eq!(
+            options.parse(" 999 345 6780"),
+            Err(ParseError::InvalidLength {
+                expected: 12,
+                actual: 13
+            })
+        );
+    }
+
+    #[test]
+    fn test_strict_with_separators() {
+        let options = ParseOptions::strict().separators(&['-', '.']);
+        assert_eq!(options.parse("999-345-6780"), Ok(expect()));
+        assert_eq!(options.parse("999.345.6780"), Ok(expect()));
+        assert_eq!(
+            options.parse("999-345.6780"),
+            Err(ParseError::MisplacedSeparator {
+                character: '.',
+                offset: 7
+            })
+        );
+        assert_eq!(
+            options.parse("999 345 6780"),
+            Err(ParseError::MisplacedSeparator {
+                character: ' ',
+                offset: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_lenient_with_separators() {
+        let options = ParseOptions::lenient();
+        assert_eq!(options.parse("999-345-6780"), Ok(expect()));
+        assert_eq!(options.parse("999.345.6780"), Ok(expect()));
+        assert_eq!(options.parse("999\u{00A0}345\u{00A0}6780"), Ok(expect()));
+        assert_eq!(options.parse("99 93 45 67 80"), Ok(expect()));
+    }
+
+    #[test]
+    fn test_lenient_with_trim() {
+        let options = ParseOptions::lenient();
+        assert_eq!(options.parse("  999 345 6780\t\n"), Ok(expect()));
+        assert_eq!(options.parse("\u{00A0}999 345 6780\u{00A0}"), Ok(expect()));
+    }
+
+    #[test]
+    fn test_lenient_with_prefixes() {
+        let options = ParseOptions::lenient();
+        assert_eq!(options.parse("NHS No: 999 345 6780"), Ok(expect()));
+        assert_eq!(options.parse("nhs number: 999 345 6780"), Ok(expect()));
+        assert_eq!(options.parse("NHS No. 9993456780"), Ok(expect()));
+        assert_eq!(options.parse("NHS 9993456780"), Ok(expect()));
+    }
+
+    #[test]
+    fn test_lenient_with_unicode_digits() {
+        let options = ParseOptions::lenient();
+        assert_eq!(options.parse("９９９ ３４５ ６７８０"), Ok(expect()));
+        assert_eq!(
+            options
+                .normalize_digits(false)
+                .parse("９９９ ３４５ ６７８０"),
+            Err(ParseError::InvalidCharacter {
+                character: '９',
+                offset: 0
+            })
+        );
+    }
+
+    #[test]
+    fn test_lenient_with_wrong_length() {
+        let options = ParseOptions::lenient();
+        assert_eq!(
+            options.parse("999-345-67801"),
+            Err(ParseError::InvalidDigitCount {
+                expected: 10,
+                actual: 11
+            })
+        );
+        assert_eq!(
+            options.parse("999-345-678"),
+            Err(ParseError::InvalidDigitCount {
+                expected: 10,
+                actual: 9
+            })
+        );
+    }
+
+    #[test]
+    fn test_lenient_with_wrong_character_offset() {
+        let options = ParseOptions::lenient();
+        assert_eq!(
+            options.parse("  NHS No: 999/345/6780"),
+            Err(ParseError::InvalidCharacter {
+                character: '/',
+                offset: 13
+            })
+        );
+    }
+
+    #[test]
+    fn test_lenient_with_wrong_check_digit() {
+        let options = ParseOptions::lenient();
+        assert_eq!(
+            options.parse("999-345-6781"),
+            Err(ParseError::InvalidCheckDigit {
+                expected: CheckDigit::Digit(0),
+                actual: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(ParseOptions::default(), ParseOptions::strict());
+    }
+}
@@ -0,0 +1,267 @@
+//! Keyed pseudonymisation of NHS Numbers, such as for research extracts.
+//!
+//! A pseudonym is the HMAC-SHA256 of the canonical ten-digit form of the NHS
+//! Number, such as "9993456780", with a secret key. The same NHS Number and
+//! key always give the same pseudonym, so datasets can be linked, yet the
+//! pseudonym cannot be reversed without the key.
+//!
+//! Each key has an id, such as "2026-q4", and each pseudonym records the id of
+//! its key, so that when a key is rotated, consumers can tell which key made
+//! which pseudonym.
+//!
+//! This module needs the feature `pseudonym`.
+//!
+//! Example:
+//!
+//! ```rust
+//! use nhs_number::NHSNumber;
+//! use nhs_number::pseudonym::{Key, pseudonymise};
+//!
+//! let key = Key::new("2026-q4", b"0123456789abcdef0123456789abcdef".to_vec()).unwrap();
+//! let nhs_number = NHSNumber::try_new([9, 9, 9, 3, 4, 5, 6, 7, 8, 0]).unwrap();
+//! let pseudonym = pseudonymise(&nhs_number, &key);
+//! assert_eq!(pseudonym.key_id(), "2026-q4");
+//! assert_eq!(
+//!     pseudonym.to_hex(),
+//!     "d44df8b50b4b534c202f0db5c533b03a4e0b6913421bd0cdcc02bf272b9d7197"
+//! );
+//! assert_eq!(
+//!     pseudonym.to_base32(),
+//!     "2RG7RNILJNJUYIBPBW24KM5QHJHAW2ITIIN5BTOMAK7SOK45OGLQ"
+//! );
+//! ```
+
+use crate::NHSNumber;
+use crate::style::Style;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+/// The minimum length of a key secret in bytes, which is the length of a
+/// SHA-256 digest.
+pub const MIN_SECRET_LEN: usize = 32;
+
+/// Key Error, which is for the constructor [Key::new].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum KeyError {
+    /// The secret is shorter than [MIN_SECRET_LEN] bytes.
+    TooShort { length: usize },
+}
+
+impl fmt::Display for KeyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            KeyError::TooShort { length } => write!(
+                f,
+                "key too short: expected at least {} bytes, found {}",
+                MIN_SECRET_LEN, length
+            ),
+        }
+    }
+}
+
+impl core::error::Error for KeyError {}
+
+/// Secret key for pseudonyms, with an id for key rotation.
+///
+/// The `Debug` implementation shows the id, and hides the secret.
+///
+#[derive(PartialEq, Eq, Clone)]
+pub struct Key {
+    id: String,
+    secret: Vec<u8>,
+}
+
+impl Key {
+    /// Create a key with an id, such as "2026-q4", and a secret, which must be
+    /// at least [MIN_SECRET_LEN] random bytes.
+    ///
+    /// There are only about a billion NHS Numbers, so a short or guessable
+    /// secret would let anyone reverse the pseudonyms by trying every number.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use nhs_number::pseudonym::{Key, KeyError};
+    /// assert!(Key::new("2026-q4", [7u8; 32].to_vec()).is_ok());
+    /// assert_eq!(
+    ///     Key::new("2026-q4", b"secret".to_vec()),
+    ///     Err(KeyError::TooShort { length: 6 })
+    /// );
+    /// ```
+    ///
+    pub fn new(id: impl Into<String>, secret: impl Into<Vec<u8>>) -> Result<Self, KeyError> {
+        let secret = secret.into();
+        if secret.len() < MIN_SECRET_LEN {
+            return Err(KeyError::TooShort {
+                length: secret.len(),
+            });
+        }
+        Ok(Key {
+            id: id.into(),
+            secret,
+        })
+    }
+
+    /// Get the key id.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl fmt::Debug for Key {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Key")
+            .field("id", &self.id)
+            .field("secret", &format_args!(".."))
+            .finish()
+    }
+}
+
+/// Pseudonym of a NHS Number, which is a HMAC-SHA256 digest, with the id of
+/// the key that made it.
+///
+/// The `Display` implementation is the lowercase hex encoding.
+///
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
+pub struct Pseudonym {
+    key_id: String,
+    digest: [u8; 32],
+}
+
+impl Pseudonym {
+    /// Get the id of the key that made the pseudonym.
+    pub fn key_id(&self) -> &str {
+        &self.key_id
+    }
+
+    /// Get the digest bytes.
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.digest
+    }
+
+    /// Encode the digest as lowercase hex, which is 64 characters.
+    pub fn to_hex(&self) -> String {
+        self.to_string()
+    }
+
+    /// Encode the digest as RFC 4648 base32, uppercase without padding, which
+    /// is 52 characters.
+    pub fn to_base32(&self) -> String {
+        const ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+        let mut encoded = String::with_capacity(52);
+        let mut buffer: u16 = 0;
+        let mut bits = 0;
+        for byte in self.digest {
+            buffer = (buffer << 8) | byte as u16;
+            bits += 8;
+            while bits >= 5 {
+                bits -= 5;
+                encoded.push(ALPHABET[((buffer >> bits) & 0x1F) as usize] as char);
+            }
+        }
+        if bits > 0 {
+            encoded.push(ALPHABET[((buffer << (5 - bits)) & 0x1F) as usize] as char);
+        }
+        encoded
+    }
+}
+
+impl fmt::Display for Pseudonym {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for byte in self.digest {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+/// Pseudonymise the NHS Number with the key.
+///
+/// The message is the canonical ten-digit form of the NHS Number, so the
+/// pseudonym does not depend on how the NHS Number was formatted.
+///
+pub fn pseudonymise(nhs_number: &NHSNumber, key: &Key) -> Pseudonym {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(&key.secret).expect("HMAC accepts a key of any length");
+    mac.update(
+        nhs_number
+            .display_with(Style::Compact)
+            .to_string()
+            .as_bytes(),
+    );
+    Pseudonym {
+        key_id: key.id.clone(),
+        digest: mac.finalize().into_bytes().into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key() -> Key {
+        Key::new("k1", b"0123456789abcdef0123456789abcdef".to_vec()).unwrap()
+    }
+
+    #[test]
+    fn test_pseudonymise() {
+        let a = NHSNumber::new_unchecked([9, 9, 9, 3, 4, 5, 6, 7, 8, 0]);
+        let b = NHSNumber::new_unchecked([9, 4, 3, 4, 7, 6, 5, 9, 1, 9]);
+        assert_eq!(
+            pseudonymise(&a, &key()).to_hex(),
+            "d44df8b50b4b534c202f0db5c533b03a4e0b6913421bd0cdcc02bf272b9d7197"
+        );
+        assert_eq!(
+            pseudonymise(&b, &key()).to_hex(),
+            "f23090a36451bfa74057a971b116cdad5a9d5c7377e58349eaf336b19a372a5d"
+        );
+    }
+
+    #[test]
+    fn test_pseudonymise_is_stable() {
+        let a = NHSNumber::new_unchecked([9, 9, 9, 3, 4, 5, 6, 7, 8, 0]);
+        assert_eq!(pseudonymise(&a, &key()), pseudonymise(&a, &key()));
+    }
+
+    #[test]
+    fn test_pseudonymise_with_rotated_key() {
+        let a = NHSNumber::new_unchecked([9, 9, 9, 3, 4, 5, 6, 7, 8, 0]);
+        let rotated = Key::new("k2", b"fedcba9876543210fedcba9876543210".to_vec()).unwrap();
+        let pseudonym = pseudonymise(&a, &rotated);
+        assert_eq!(pseudonym.key_id(), "k2");
+        assert_eq!(
+            pseudonym.to_base32(),
+            "27DFWGMYKU56EOEEC5PTTWLNRBEYPEHDJGOHSCQ2ZVTQW63ULDRA"
+        );
+        assert_ne!(pseudonym, pseudonymise(&a, &key()));
+    }
+
+    #[test]
+    fn test_base32() {
+        let b = NHSNumber::new_unchecked([9, 4, 3, 4, 7, 6, 5, 9, 1, 9]);
+        assert_eq!(
+            pseudonymise(&b, &key()).to_base32(),
+            "6IYJBI3EKG72OQCXVFY3CFWNVVNJ2XDTO7SYGSPK6M3LDGRXFJOQ"
+        );
+    }
+
+    #[test]
+    fn test_key_too_short() {
+        assert_eq!(
+            Key::new("k1", [7u8; 31].to_vec()),
+            Err(KeyError::TooShort { length: 31 })
+        );
+        assert_eq!(
+            KeyError::TooShort { length: 31 }.to_string(),
+            "key too short: expected at least 32 bytes, found 31"
+        );
+    }
+
+    #[test]
+    fn test_key_debug_hides_secret() {
+        assert_eq!(format!("{:?}", key()), "Key { id: \"k1\", secret: .. }");
+    }
+}
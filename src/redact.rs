@@ -0,0 +1,322 @@
+//! Redact NHS Numbers from text and from JSON values, such as for scrubbing
+//! logs, error messages, and exports.
+//!
+//! The redactor finds NHS Numbers via [scan_all](crate::scan::scan_all), so it
+//! replaces each NHS Number with a valid check digit, in any of the formats
+//! that the scanner finds, and leaves other digits unchanged. A NHS Number
+//! next to a date, a count, another NHS Number, or even a phone number prefix
+//! is still replaced, because a redactor must not leave a NHS Number in plain
+//! text.
+//!
+//! Each NHS Number is replaced with one of:
+//!
+//! * [Replacement::Mask] is the masked NHS Number, such as "*** *** ****".
+//!
+//! * [Replacement::Token] is a fixed token, such as "[NHS NUMBER]".
+//!
+//! * `Replacement::Pseudonym` is the keyed pseudonym in hex, which needs the
+//!   feature `pseudonym`.
+//!
+//! * [Replacement::Custom] is the result of a function.
+//!
+//! Example:
+//!
+//! ```rust
+//! use nhs_number::redact::{Redactor, Replacement, redact};
+//!
+//! let text = "Lookup failed for 999 345 6780 at 10:42";
+//! assert_eq!(redact(text), "Lookup failed for *** *** **** at 10:42");
+//!
+//! let redactor = Redactor::new(Replacement::Token("[NHS NUMBER]"));
+//! assert_eq!(redactor.redact(text), "Lookup failed for [NHS NUMBER] at 10:42");
+//! ```
+
+use crate::NHSNumber;
+use crate::mask::MaskPolicy;
+use crate::scan::scan_all;
+use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
+use core::fmt;
+
+/// Redact NHS Numbers from the text, with every digit masked.
+///
+/// Return the text borrowed if it has no NHS Numbers.
+///
+/// Example:
+///
+/// ```rust
+/// use nhs_number::redact::redact;
+/// assert_eq!(redact("NHS No: 9993456780"), "NHS No: *** *** ****");
+/// ```
+///
+pub fn redact(text: &str) -> Cow<'_, str> {
+    Redactor::default().redact(text)
+}
+
+/// The replacement for each NHS Number.
+#[derive(Clone, Copy)]
+pub enum Replacement<'a> {
+    /// Replace with the NHS Number masked by the policy.
+    Mask(MaskPolicy),
+
+    /// Replace with a fixed token.
+    Token(&'a str),
+
+    /// Replace with the keyed pseudonym in hex.
+    #[cfg(feature = "pseudonym")]
+    Pseudonym(&'a crate::pseudonym::Key),
+
+    /// Replace with the result of a function.
+    Custom(&'a dyn Fn(NHSNumber) -> String),
+}
+
+impl fmt::Debug for Replacement<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Replacement::Mask(policy) => f.debug_tuple("Mask").field(policy).finish(),
+            Replacement::Token(token) => f.debug_tuple("Token").field(token).finish(),
+            #[cfg(feature = "pseudonym")]
+            Replacement::Pseudonym(key) => f.debug_tuple("Pseudonym").field(key).finish(),
+            Replacement::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+/// NHS Number redactor, which replaces each NHS Number in text or in a JSON
+/// value.
+///
+/// The default redactor masks every digit, such as "*** *** ****".
+///
+#[derive(Debug, Clone, Copy)]
+pub struct Redactor<'a> {
+    replacement: Replacement<'a>,
+}
+
+impl<'a> Redactor<'a> {
+    /// Create a redactor with the replacement.
+    pub fn new(replacement: Replacement<'a>) -> Self {
+        Redactor { replacement }
+    }
+
+    /// Get the replacement text for the NHS Number.
+    pub fn replace(&self, nhs_number: NHSNumber) -> String {
+        match self.replacement {
+            Replacement::Mask(policy) => nhs_number.masked_with(policy).to_string(),
+            Replacement::Token(token) => token.to_string(),
+            #[cfg(feature = "pseudonym")]
+            Replacement::Pseudonym(key) => {
+                crate::pseudonym::pseudonymise(&nhs_number, key).to_hex()
+            }
+            Replacement::Custom(function) => function(nhs_number),
+        }
+    }
+
+    /// Redact NHS Numbers from the text.
+    ///
+    /// Return the text borrowed if it has no NHS Numbers.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use nhs_number::redact::{Redactor, Replacement};
+    /// use nhs_number::mask::MaskPolicy;
+    ///
+    /// let redactor = Redactor::new(Replacement::Mask(MaskPolicy::trailing(4)));
+    /// assert_eq!(redactor.redact("9993456780, 943-476-5919"), "*** *** 6780, *** *** 5919");
+    /// ```
+    ///
+    pub fn redact<'t>(&self, text: &'t str) -> Cow<'t, str> {
+        let mut matches = scan_all(text).peekable();
+        if matches.peek().is_none() {
+            return Cow::Borrowed(text);
+        }
+        let mut redacted = String::with_capacity(text.len());
+        let mut last = 0;
+        for found in matches {
+            redacted.push_str(&text[last..found.start()]);
+            redacted.push_str(&self.replace(found.nhs_number()));
+            last = found.end();
+        }
+        redacted.push_str(&text[last..]);
+        Cow::Owned(redacted)
+    }
+
+    /// Redact NHS Numbers from the JSON value, recursively, in place.
+    ///
+    /// This redacts each string, each object key, and each integer with ten
+    /// digits, such as 9993456780, which becomes a string. An integer with
+    /// fewer digits is unchanged, even if it could be a NHS Number with
+    /// leading zeros, because most such integers are not NHS Numbers.
+    ///
+    /// If two object keys redact to the same key, then the last one wins.
+    ///
+    /// This method needs the feature `serde_json`.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use nhs_number::redact::{Redactor, Replacement};
+    /// use serde_json::json;
+    ///
+    /// let mut value = json!({"message": "not found: 999 345 6780", "nhs": 9993456780u64, "id": 42});
+    /// Redactor::new(Replacement::Token("[NHS]")).redact_json(&mut value);
+    /// assert_eq!(value, json!({"message": "not found: [NHS]", "nhs": "[NHS]", "id": 42}));
+    /// ```
+    ///
+    #[cfg(feature = "serde_json")]
+    pub fn redact_json(&self, value: &mut serde_json::Value) {
+        use serde_json::Value;
+        match value {
+            Value::String(s) => {
+                if let Cow::Owned(redacted) = self.redact(s) {
+                    *s = redacted;
+                }
+            }
+            Value::Number(number) => {
+                if let Some(integer) = number.as_u64()
+                    && (1_000_000_000..=9_999_999_999).contains(&integer)
+                    && let Ok(nhs_number) = NHSNumber::try_from(integer)
+                {
+                    *value = Value::String(self.replace(nhs_number));
+                }
+            }
+            Value::Array(values) => {
+                for value in values {
+                    self.redact_json(value);
+                }
+            }
+            Value::Object(map) => {
+                *map = std::mem::take(map)
+                    .into_iter()
+                    .map(|(key, mut value)| {
+                        self.redact_json(&mut value);
+                        (self.redact(&key).into_owned(), value)
+                    })
+                    .collect();
+            }
+            Value::Null | Value::Bool(_) => {}
+        }
+    }
+}
+
+/// The default redactor masks every digit.
+impl Default for Redactor<'_> {
+    fn default() -> Self {
+        Redactor::new(Replacement::Mask(MaskPolicy::all()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::Style;
+
+    #[test]
+    fn test_redact() {
+        assert_eq!(
+            redact("a 999 345 6780 b 943-476-5919 c"),
+            "a *** *** **** b *** *** **** c"
+        );
+    }
+
+    #[test]
+    fn test_redact_next_to_other_digit_groups() {
+        assert_eq!(
+            redact("NHS 999 345 6780 2024-03-01 admitted"),
+            "NHS *** *** **** 2024-03-01 admitted"
+        );
+        assert_eq!(redact("Bed 4 999 345 6780"), "Bed 4 *** *** ****");
+        assert_eq!(
+            redact("ids 999 345 6780 943 476 5919"),
+            "ids *** *** **** *** *** ****"
+        );
+    }
+
+    #[test]
+    fn test_redact_after_dates_and_zero_led_groups() {
+        assert_eq!(
+            redact("Admitted 2024-03-01 943 476 5919"),
+            "Admitted 2024-03-01 *** *** ****"
+        );
+        assert_eq!(redact("seen 01/02 943 476 5919"), "seen 01/02 *** *** ****");
+        assert_eq!(redact("Ward 07 999 345 6780"), "Ward 07 *** *** ****");
+        assert_eq!(redact("tel +44 999 345 6780"), "tel +44 *** *** ****");
+    }
+
+    #[test]
+    fn test_redact_without_nhs_numbers_is_borrowed() {
+        let text = "tel 0123456780, ref 12345";
+        assert!(matches!(redact(text), Cow::Borrowed(s) if s == text));
+    }
+
+    #[test]
+    fn test_redact_with_token() {
+        let redactor = Redactor::new(Replacement::Token("<redacted>"));
+        assert_eq!(
+            redactor.redact("9993456780/9434765919"),
+            "<redacted>/<redacted>"
+        );
+    }
+
+    #[test]
+    fn test_redact_with_mask_policy() {
+        let policy = MaskPolicy::trailing(2).mask('x').style(Style::Compact);
+        let redactor = Redactor::new(Replacement::Mask(policy));
+        assert_eq!(redactor.redact("[999 345 6780]"), "[xxxxxxxx80]");
+    }
+
+    #[test]
+    fn test_redact_with_custom() {
+        let pseudonym = |nhs_number: NHSNumber| format!("P{}", u64::from(nhs_number) % 1000);
+        let redactor = Redactor::new(Replacement::Custom(&pseudonym));
+        assert_eq!(redactor.redact("id=9993456780"), "id=P780");
+    }
+
+    #[cfg(feature = "pseudonym")]
+    #[test]
+    fn test_redact_with_pseudonym() {
+        let key =
+            crate::pseudonym::Key::new("k1", b"0123456789abcdef0123456789abcdef".to_vec()).unwrap();
+        let redactor = Redactor::new(Replacement::Pseudonym(&key));
+        assert_eq!(
+            redactor.redact("id=9993456780"),
+            "id=d44df8b50b4b534c202f0db5c533b03a4e0b6913421bd0cdcc02bf272b9d7197"
+        );
+    }
+
+    #[test]
+    fn test_replacement_debug() {
+        assert_eq!(format!("{:?}", Replacement::Token("x")), "Token(\"x\")");
+        let function = |_| String::new();
+        assert_eq!(
+            format!("{:?}", Replacement::Custom(&function)),
+            "Custom(..)"
+        );
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_redact_json() {
+        use serde_json::json;
+        let mut value = json!({
+            "9993456780": {"nested": ["ok", "NHS 999 345 6780"]},
+            "small": 123456789,
+            "large": 9993456780u64,
+            "invalid": 9993456781u64,
+            "flag": true,
+            "none": null,
+        });
+        Redactor::default().redact_json(&mut value);
+        assert_eq!(
+            value,
+            json!({
+                "*** *** ****": {"nested": ["ok", "NHS *** *** ****"]},
+                "small": 123456789,
+                "large": "*** *** ****",
+                "invalid": 9993456781u64,
+                "flag": true,
+                "none": null,
+            })
+        );
+    }
+}
@@ -0,0 +1,341 @@
+//! Find NHS Numbers in free text, such as clinical letters and notes.
+//!
+//! The scanner finds each candidate that is either 10 consecutive digits, or
+//! digits in '3 3 4' format with the same separator twice, such as a space, a
+//! hyphen, a non-breaking space, or a dot; see
+//! [LENIENT_SEPARATORS](crate::parse_options::LENIENT_SEPARATORS).
+//!
+//! A candidate must not be part of a longer sequence of digits, so the scanner
+//! skips digits within a longer number. The scanner also skips a grouped
+//! candidate after an international phone number prefix, which is a digit
+//! group that follows a '+' or starts with "00", such as "+44 999 345 6780" or
+//! "0044 999 345 6780". Any other neighbouring digit group does not skip a
+//! candidate, so that two adjacent NHS Numbers, or a NHS Number next to a date
+//! or a ward number, each still match.
+//!
+//! To find every candidate, including any after a phone number prefix, use
+//! [scan_all], such as for redaction, which must not leave a NHS Number in
+//! plain text.
+//!
+//! The scanner then keeps each candidate whose check digit is valid, which
+//! suppresses most phone numbers and other ten-digit sequences. To also keep
+//! only numbers that pass the full validation, filter the matches by
+//! [NHSNumber::validate].
+//!
+//! Example:
+//!
+//! ```rust
+//! let text = "Patient 999 345 6780 (tel 0123456780) was referred by 943-476-5919.";
+//! let matches: Vec<_> = nhs_number::scan(text).collect();
+//! assert_eq!(matches.len(), 2);
+//! assert_eq!(matches[0].as_str(), "999 345 6780");
+//! assert_eq!(matches[0].range(), 8..20);
+//! assert_eq!(matches[1].nhs_number().to_string(), "943 476 5919");
+//! ```
+
+use crate::NHSNumber;
+use crate::parse_options::LENIENT_SEPARATORS;
+use core::fmt;
+use core::ops::Range;
+
+/// Scan the text for NHS Numbers, and return an iterator of matches, in order.
+///
+/// Example:
+///
+/// ```rust
+/// let text = "NHS No: 9993456780.";
+/// let found = nhs_number::scan(text).next().unwrap();
+/// assert_eq!(found.start(), 8);
+/// assert_eq!(found.end(), 18);
+/// ```
+///
+pub fn scan(text: &str) -> Scan<'_> {
+    Scan {
+        text,
+        position: 0,
+        skip_prefixed: true,
+    }
+}
+
+/// Scan the text for NHS Numbers, including any after a phone number prefix,
+/// and return an iterator of matches, in order.
+///
+/// Example:
+///
+/// ```rust
+/// use nhs_number::scan::{scan, scan_all};
+/// let text = "tel +44 999 345 6780";
+/// assert_eq!(scan(text).count(), 0);
+/// assert_eq!(scan_all(text).next().unwrap().as_str(), "999 345 6780");
+/// ```
+///
+pub fn scan_all(text: &str) -> Scan<'_> {
+    Scan {
+        text,
+        position: 0,
+        skip_prefixed: false,
+    }
+}
+
+/// Iterator of NHS Number matches in text, which is created by [scan] or
+/// [scan_all].
+///
+/// The `Debug` implementation omits the scanned text.
+#[derive(Clone)]
+pub struct Scan<'a> {
+    text: &'a str,
+    position: usize,
+    skip_prefixed: bool,
+}
+
+impl fmt::Debug for Scan<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Scan")
+            .field("text", &format_args!(".."))
+            .field("position", &self.position)
+            .field("skip_prefixed", &self.skip_prefixed)
+            .finish()
+    }
+}
+
+/// NHS Number match in text, with its byte offsets.
+///
+/// The `Debug` implementation omits the scanned text, and masks the NHS
+/// Number, so that a stray `{:?}` does not leak the text or the NHS Number.
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub struct Match<'a> {
+    text: &'a str,
+    start: usize,
+    end: usize,
+    nhs_number: NHSNumber,
+}
+
+impl<'a> Match<'a> {
+    /// Get the byte offset of the start of the match.
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Get the byte offset of the end of the match, which is exclusive.
+    pub fn end(&self) -> usize {
+        self.end
+    }
+
+    /// Get the byte range of the match.
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+
+    /// Get the matched text, as it is in the scanned text.
+    pub fn as_str(&self) -> &'a str {
+        &self.text[self.start..self.end]
+    }
+
+    /// Get the NHS Number.
+    pub fn nhs_number(&self) -> NHSNumber {
+        self.nhs_number
+    }
+}
+
+impl fmt::Debug for Match<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Match")
+            .field("text", &format_args!(".."))
+            .field("start", &self.start)
+            .field("end", &self.end)
+            .field("nhs_number", &self.nhs_number)
+            .finish()
+    }
+}
+
+impl<'a> Iterator for Scan<'a> {
+    type Item = Match<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(offset) = self.text[self.position..].find(|c: char| c.is_ascii_digit()) {
+            let start = self.position + offset;
+            self.position = start + 1;
+            if let Some((end, digits)) = candidate(self.text, start, self.skip_prefixed)
+                && let Ok(nhs_number) = NHSNumber::try_new(digits)
+            {
+                self.position = end;
+                return Some(Match {
+                    text: self.text,
+                    start,
+                    end,
+                    nhs_number,
+                });
+            }
+        }
+        self.position = self.text.len();
+        None
+    }
+}
+
+impl core::iter::FusedIterator for Scan<'_> {}
+
+/// Find a candidate that starts at the byte offset, and return its end byte
+/// offset and its digits. If `skip_prefixed` is true, then skip a grouped
+/// candidate after an international phone number prefix.
+fn candidate(text: &str, start: usize, skip_prefixed: bool) -> Option<(usize, [i8; 10])> {
+    let before = &text[..start];
+    let previous = before.chars().next_back();
+    if previous.is_some_and(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let mut chars = text[start..].char_indices().peekable();
+    let mut digits = [0i8; 10];
+    let mut separator = None;
+    for (i, digit) in digits.iter_mut().enumerate() {
+        if i == 3 {
+            // The first group decides the format: compact, or grouped with
+            // this separator.
+            if let Some(&(_, c)) = chars.peek()
+                && LENIENT_SEPARATORS.contains(&c)
+            {
+                separator = Some(c);
+                chars.next();
+            }
+        } else if i == 6
+            && let Some(separator) = separator
+            && chars.next().map(|(_, c)| c) != Some(separator)
+        {
+            return None;
+        }
+        match chars.next() {
+            Some((_, c)) if c.is_ascii_digit() => *digit = (c as u8 - b'0') as i8,
+            _ => return None,
+        }
+    }
+    let end = chars
+        .peek()
+        .map_or(text.len(), |(offset, _)| start + offset);
+    if text[end..].starts_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+    if skip_prefixed
+        && let Some(separator) = separator
+        && previous == Some(separator)
+    {
+        // Skip a grouped candidate after an international phone number prefix.
+        let rest = &before[..before.len() - separator.len_utf8()];
+        let outside = rest.trim_end_matches(|c: char| c.is_ascii_digit());
+        let group = &rest[outside.len()..];
+        if !group.is_empty() && (outside.ends_with('+') || group.starts_with("00")) {
+            return None;
+        }
+    }
+    Some((end, digits))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Scan the text, and return the matched strings.
+    fn found(text: &str) -> Vec<&str> {
+        scan(text).map(|m| m.as_str()).collect()
+    }
+
+    #[test]
+    fn test_scan_formats() {
+        assert_eq!(found("9993456780"), ["9993456780"]);
+        assert_eq!(found("999 345 6780"), ["999 345 6780"]);
+        assert_eq!(found("999-345-6780"), ["999-345-6780"]);
+        assert_eq!(found("999.345.6780"), ["999.345.6780"]);
+        assert_eq!(
+            found("999\u{00A0}345\u{00A0}6780"),
+            ["999\u{00A0}345\u{00A0}6780"]
+        );
+    }
+
+    #[test]
+    fn test_scan_in_prose() {
+        let text = "Seen today (NHS 999 345 6780). Previous: 9434765919, 4010232137.";
+        assert_eq!(found(text), ["999 345 6780", "9434765919", "4010232137"]);
+    }
+
+    #[test]
+    fn test_scan_offsets_with_multibyte_text() {
+        let text = "Café → 999 345 6780";
+        let m = scan(text).next().unwrap();
+        assert_eq!(m.range(), 10..22);
+        assert_eq!(&text[m.range()], "999 345 6780");
+        assert_eq!(
+            m.nhs_number(),
+            NHSNumber::new_unchecked([9, 9, 9, 3, 4, 5, 6, 7, 8, 0])
+        );
+    }
+
+    #[test]
+    fn test_scan_skips_wrong_check_digit() {
+        assert!(found("Call 999 345 6781 or 0123456780").is_empty());
+    }
+
+    #[test]
+    fn test_scan_skips_mixed_separators() {
+        assert!(found("999 345-6780").is_empty());
+        assert!(found("999 3456780").is_empty());
+        assert!(found("9993 456780").is_empty());
+    }
+
+    #[test]
+    fn test_scan_skips_longer_numbers() {
+        assert!(found("19993456780").is_empty());
+        assert!(found("99934567801").is_empty());
+        assert!(found("+44 999 345 6780").is_empty());
+        assert!(found("0044 999 345 6780").is_empty());
+        assert!(found("tel +44-999-345-6780").is_empty());
+    }
+
+    #[test]
+    fn test_scan_allows_adjacent_digit_groups() {
+        assert_eq!(
+            found("999 345 6780 943 476 5919"),
+            ["999 345 6780", "943 476 5919"]
+        );
+        assert_eq!(found("Bed 4 999 345 6780"), ["999 345 6780"]);
+        assert_eq!(found("999 345 6780 2024-03-01"), ["999 345 6780"]);
+        assert_eq!(found("999 345 6780 12"), ["999 345 6780"]);
+    }
+
+    #[test]
+    fn test_scan_allows_preceding_dates_and_zero_led_groups() {
+        assert_eq!(found("Admitted 2024-03-01 943 476 5919"), ["943 476 5919"]);
+        assert_eq!(found("seen 01/02 943 476 5919"), ["943 476 5919"]);
+        assert_eq!(found("Ward 07 999 345 6780"), ["999 345 6780"]);
+        assert_eq!(found("01 943 476 5919"), ["943 476 5919"]);
+        assert_eq!(found("0 943-476-5919"), ["943-476-5919"]);
+    }
+
+    #[test]
+    fn test_scan_all_includes_prefixed() {
+        let found = |text| scan_all(text).map(|m| m.as_str()).collect::<Vec<_>>();
+        assert_eq!(found("+44 999 345 6780"), ["999 345 6780"]);
+        assert_eq!(found("0044 999 345 6780"), ["999 345 6780"]);
+        assert!(found("19993456780").is_empty());
+    }
+
+    #[test]
+    fn test_scan_allows_adjacent_punctuation() {
+        assert_eq!(found("(9993456780)"), ["9993456780"]);
+        assert_eq!(found("NHS:999-345-6780;"), ["999-345-6780"]);
+        assert_eq!(found("9993456780,9434765919"), ["9993456780", "9434765919"]);
+    }
+
+    #[test]
+    fn test_match_debug_omits_text() {
+        let m = scan("patient 9993456780").next().unwrap();
+        assert_eq!(
+            format!("{:?}", m),
+            "Match { text: .., start: 8, end: 18, nhs_number: NHSNumber(*** *** 6780) }"
+        );
+    }
+
+    #[test]
+    fn test_scan_empty() {
+        assert!(found("").is_empty());
+        assert!(found("no numbers here").is_empty());
+        assert!(found("999 345").is_empty());
+    }
+}
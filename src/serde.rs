@@ -0,0 +1,311 @@
+//! Serde helpers for NHS Number, which choose the serialized representation.
+//!
+//! Each helper module is for the serde attribute `with`:
+//!
+//! * [compact] serializes as a string of 10 digits e.g. "9993456780".
+//!
+//! * [spaced] serializes as a string in '3 3 4' format e.g. "999 345 6780".
+//!
+//! * [integer] serializes as an integer e.g. 9993456780.
+//!
+//! For a human-readable format, such as JSON, each helper deserializes any of
+//! these representations. For a compact format, such as postcard or bincode,
+//! each helper deserializes only its own representation. Each helper validates
+//! the NHS Number, including its check digit.
+//!
+//! Without a helper, NHS Number serializes as a struct of digits, such as
+//! `{"digits":[9,9,9,3,4,5,6,7,8,0]}`, and deserializes with validation.
+//!
+//! Example:
+//!
+//! ```rust
+//! use nhs_number::NHSNumber;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Patient {
+//!     #[serde(with = "nhs_number::serde::compact")]
+//!     nhs_number: NHSNumber,
+//! }
+//!
+//! let patient: Patient = serde_json::from_str(r#"{"nhs_number":"999 345 6780"}"#).unwrap();
+//! let json = serde_json::to_string(&patient).unwrap();
+//! assert_eq!(json, r#"{"nhs_number":"9993456780"}"#);
+//!
+//! let result = serde_json::from_str::<Patient>(r#"{"nhs_number":"999 345 6781"}"#);
+//! assert!(result.is_err());
+//! ```
+
+use crate::NHSNumber;
+use ::serde::Deserialize;
+use ::serde::de::{self, Deserializer, Visitor};
+use core::fmt;
+use core::str::FromStr;
+
+/// Serialize and deserialize a NHS Number as a string of 10 digits, such as
+/// "9993456780".
+pub mod compact {
+    use crate::NHSNumber;
+    use crate::style::Style;
+    use ::serde::{Deserializer, Serializer};
+
+    /// Serialize a NHS Number as a string of 10 digits.
+    pub fn serialize<S: Serializer>(
+        nhs_number: &NHSNumber,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&nhs_number.display_with(Style::Compact))
+    }
+
+    /// Deserialize a NHS Number from a string, or from a string or an integer
+    /// for a human-readable format, with validation.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<NHSNumber, D::Error> {
+        super::deserialize_str(deserializer)
+    }
+}
+
+/// Serialize and deserialize a NHS Number as a string in '3 3 4' format, such
+/// as "999 345 6780".
+pub mod spaced {
+    use crate::NHSNumber;
+    use ::serde::{Deserializer, Serializer};
+
+    /// Serialize a NHS Number as a string in '3 3 4' format.
+    pub fn serialize<S: Serializer>(
+        nhs_number: &NHSNumber,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(nhs_number)
+    }
+
+    /// Deserialize a NHS Number from a string, or from a string or an integer
+    /// for a human-readable format, with validation.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<NHSNumber, D::Error> {
+        super::deserialize_str(deserializer)
+    }
+}
+
+/// Serialize and deserialize a NHS Number as an integer, such as 9993456780.
+///
+/// A NHS Number that starts with zero serializes as a smaller integer, such as
+/// 123456789 for "012 345 6789".
+pub mod integer {
+    use crate::NHSNumber;
+    use ::serde::{Deserializer, Serializer};
+
+    /// Serialize a NHS Number as an integer.
+    pub fn serialize<S: Serializer>(
+        nhs_number: &NHSNumber,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(u64::from(*nhs_number))
+    }
+
+    /// Deserialize a NHS Number from an integer, or from a string or an
+    /// integer for a human-readable format, with validation.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<NHSNumber, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(super::NHSNumberVisitor)
+        } else {
+            deserializer.deserialize_u64(super::NHSNumberVisitor)
+        }
+    }
+}
+
+/// Deserialize a NHS Number from a string, or from a string or an integer for
+/// a human-readable format, with validation.
+///
+/// A format that is not self-describing, such as postcard, cannot deserialize
+/// any type, so needs the type that the helper serializes.
+fn deserialize_str<'de, D: Deserializer<'de>>(deserializer: D) -> Result<NHSNumber, D::Error> {
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(NHSNumberVisitor)
+    } else {
+        deserializer.deserialize_str(NHSNumberVisitor)
+    }
+}
+
+/// Visitor that accepts a NHS Number string or integer.
+struct NHSNumberVisitor;
+
+impl Visitor<'_> for NHSNumberVisitor {
+    type Value = NHSNumber;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a NHS Number string or integer")
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<Self::Value, E> {
+        NHSNumber::from_str(s).map_err(E::custom)
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        NHSNumber::try_from(value).map_err(E::custom)
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+        let value = u64::try_from(value)
+            .map_err(|_| E::invalid_value(de::Unexpected::Signed(value), &self))?;
+        self.visit_u64(value)
+    }
+}
+
+/// Representation of NHS Number that serde derives, which is the same as the
+/// representation that `Serialize` uses, and which is not yet validated.
+#[derive(Deserialize)]
+#[serde(rename = "NHSNumber")]
+struct Digits {
+    digits: [i8; 10],
+}
+
+/// Deserialize a NHS Number from a struct of digits, with validation.
+///
+/// Example:
+///
+/// ```rust
+/// use nhs_number::NHSNumber;
+///
+/// let json = r#"{"digits":[9,9,9,3,4,5,6,7,8,0]}"#;
+/// let nhs_number: NHSNumber = serde_json::from_str(json).unwrap();
+/// assert_eq!(nhs_number.to_string(), "999 345 6780");
+///
+/// let json = r#"{"digits":[9,9,9,3,4,5,6,7,8,1]}"#;
+/// let error = serde_json::from_str::<NHSNumber>(json).unwrap_err();
+/// assert_eq!(error.to_string(), "invalid check digit: expected 0, found 1");
+/// ```
+///
+impl<'de> Deserialize<'de> for NHSNumber {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let Digits { digits } = Digits::deserialize(deserializer)?;
+        NHSNumber::try_new(digits).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Compact(#[serde(with = "compact")] NHSNumber);
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Spaced(#[serde(with = "spaced")] NHSNumber);
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Integer(#[serde(with = "integer")] NHSNumber);
+
+    fn nhs_number() -> NHSNumber {
+        NHSNumber::new_unchecked([9, 9, 9, 3, 4, 5, 6, 7, 8, 0])
+    }
+
+    #[test]
+    fn test_compact() {
+        let json = serde_json::to_string(&Compact(nhs_number())).unwrap();
+        assert_eq!(json, r#""9993456780""#);
+        let actual: Compact = serde_json::from_str(&json).unwrap();
+        assert_eq!(actual, Compact(nhs_number()));
+    }
+
+    #[test]
+    fn test_spaced() {
+        let json = serde_json::to_string(&Spaced(nhs_number())).unwrap();
+        assert_eq!(json, r#""999 345 6780""#);
+        let actual: Spaced = serde_json::from_str(&json).unwrap();
+        assert_eq!(actual, Spaced(nhs_number()));
+    }
+
+    #[test]
+    fn test_integer() {
+        let json = serde_json::to_string(&Integer(nhs_number())).unwrap();
+        assert_eq!(json, "9993456780");
+        let actual: Integer = serde_json::from_str(&json).unwrap();
+        assert_eq!(actual, Integer(nhs_number()));
+    }
+
+    #[test]
+    fn test_integer_with_leading_zero() {
+        let a = NHSNumber::new_unchecked([0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        let json = serde_json::to_string(&Integer(a)).unwrap();
+        assert_eq!(json, "123456789");
+        let actual: Integer = serde_json::from_str(&json).unwrap();
+        assert_eq!(actual, Integer(a));
+    }
+
+    #[test]
+    fn test_deserialize_any_representation() {
+        for json in [r#""9993456780""#, r#""999 345 6780""#, "9993456780"] {
+            let compact: Compact = serde_json::from_str(json).unwrap();
+            let spaced: Spaced = serde_json::from_str(json).unwrap();
+            let integer: Integer = serde_json::from_str(json).unwrap();
+            assert_eq!(compact.0, nhs_number());
+            assert_eq!(spaced.0, nhs_number());
+            assert_eq!(integer.0, nhs_number());
+        }
+    }
+
+    #[test]
+    fn test_round_trip_with_non_self_describing_format() {
+        let bytes = postcard::to_allocvec(&Compact(nhs_number())).unwrap();
+        let actual: Compact = postcard::from_bytes(&bytes).unwrap();
+        assert_eq!(actual, Compact(nhs_number()));
+        let bytes = postcard::to_allocvec(&Spaced(nhs_number())).unwrap();
+        let actual: Spaced = postcard::from_bytes(&bytes).unwrap();
+        assert_eq!(actual, Spaced(nhs_number()));
+        let bytes = postcard::to_allocvec(&Integer(nhs_number())).unwrap();
+        let actual: Integer = postcard::from_bytes(&bytes).unwrap();
+        assert_eq!(actual, Integer(nhs_number()));
+    }
+
+    #[test]
+    fn test_deserialize_with_wrong_check_digit() {
+        let error = serde_json::from_str::<Compact>(r#""999 345 6781""#).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "invalid check digit: expected 0, found 1 at line 1 column 14"
+        );
+        let error = serde_json::from_str::<Integer>("9993456781").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "invalid check digit: expected 0, found 1 at line 1 column 10"
+        );
+    }
+
+    #[test]
+    fn test_deserialize_with_wrong_type() {
+        assert!(serde_json::from_str::<Compact>("-9993456780").is_err());
+        assert!(serde_json::from_str::<Compact>("true").is_err());
+        assert!(serde_json::from_str::<Compact>("[9,9,9]").is_err());
+    }
+
+    #[test]
+    fn test_nhs_number_round_trip() {
+        let json = serde_json::to_string(&nhs_number()).unwrap();
+        assert_eq!(json, r#"{"digits":[9,9,9,3,4,5,6,7,8,0]}"#);
+        let actual: NHSNumber = serde_json::from_str(&json).unwrap();
+        assert_eq!(actual, nhs_number());
+    }
+
+    #[test]
+    fn test_nhs_number_deserialize_with_invalid_digit() {
+        let json = r#"{"digits":[9,9,9,3,4,5,6,7,-8,0]}"#;
+        let error = serde_json::from_str::<NHSNumber>(json).unwrap_err();
+        assert_eq!(error.to_string(), "invalid digit -8 at index 8");
+    }
+
+    #[test]
+    fn test_nhs_number_deserialize_with_wrong_check_digit() {
+        let json = r#"{"digits":[9,9,9,1,2,3,4,5,6,0]}"#;
+        let error = serde_json::from_str::<NHSNumber>(json).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "invalid check digit: the first nine digits can never have a valid check digit, found 0"
+        );
+    }
+
+    #[test]
+    fn test_nhs_number_deserialize_with_wrong_shape() {
+        assert!(serde_json::from_str::<NHSNumber>(r#"{"digits":[9,9,9]}"#).is_err());
+        assert!(serde_json::from_str::<NHSNumber>(r#""9993456780""#).is_err());
+    }
+}
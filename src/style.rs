@@ -0,0 +1,138 @@
+use crate::NHSNumber;
+use core::fmt;
+
+/// NHS Number display style, which chooses the separator between the digit
+/// groups in '3 3 4' format.
+///
+/// Example:
+///
+/// ```rust
+/// use nhs_number::{NHSNumber, style::Style};
+/// let nhs_number = NHSNumber::try_new([9, 9, 9, 3, 4, 5, 6, 7, 8, 0]).unwrap();
+/// assert_eq!(nhs_number.display_with(Style::Spaced).to_string(), "999 345 6780");
+/// assert_eq!(nhs_number.display_with(Style::Compact).to_string(), "9993456780");
+/// assert_eq!(nhs_number.display_with(Style::Hyphenated).to_string(), "999-345-6780");
+/// ```
+///
+/// The `Display` implementation of NHS Number also supports format flags:
+///
+/// ```rust
+/// use nhs_number::NHSNumber;
+/// let nhs_number = NHSNumber::try_new([9, 9, 9, 3, 4, 5, 6, 7, 8, 0]).unwrap();
+/// assert_eq!(format!("{}", nhs_number), "999 345 6780");
+/// assert_eq!(format!("{:#}", nhs_number), "9993456780");
+/// assert_eq!(format!("{:-}", nhs_number), "999-345-6780");
+/// ```
+///
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Style {
+    /// Spaces between the digit groups e.g. "999 345 6780", such as for letters.
+    #[default]
+    Spaced,
+
+    /// No separators e.g. "9993456780", such as for HL7 feeds.
+    Compact,
+
+    /// Hyphens between the digit groups e.g. "999-345-6780", such as for
+    /// legacy screens.
+    Hyphenated,
+}
+
+impl Style {
+    /// Get the separator between the digit groups, if any.
+    pub fn separator(&self) -> Option<char> {
+        match self {
+            Style::Spaced => Some(' '),
+            Style::Compact => None,
+            Style::Hyphenated => Some('-'),
+        }
+    }
+}
+
+/// NHS Number with a display style, which implements `Display` without
+/// allocating.
+///
+/// Create this by calling the method [NHSNumber::display_with].
+///
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Styled {
+    nhs_number: NHSNumber,
+    style: Style,
+}
+
+impl Styled {
+    /// Create a NHS Number with a display style.
+    pub fn new(nhs_number: NHSNumber, style: Style) -> Self {
+        Styled { nhs_number, style }
+    }
+}
+
+impl fmt::Display for Styled {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_digits(f, self.nhs_number.digits(), self.style, |_| None)
+    }
+}
+
+/// Format digits with a style, without allocating, and with padding.
+///
+/// The function `mask` can replace the digit at an index with a character.
+/// Any digit that is not in the range 0–9 is formatted as '?'.
+pub(crate) fn fmt_digits(
+    f: &mut fmt::Formatter,
+    digits: [i8; 10],
+    style: Style,
+    mask: impl Fn(usize) -> Option<char>,
+) -> fmt::Result {
+    // Each of 10 digits and 2 separators needs at most 4 bytes in UTF-8.
+    let mut buffer = [0u8; 48];
+    let mut len = 0;
+    for (i, digit) in digits.iter().enumerate() {
+        if (i == 3 || i == 6)
+            && let Some(separator) = style.separator()
+        {
+            len += separator.encode_utf8(&mut buffer[len..]).len();
+        }
+        let character = mask(i)
+            .or_else(|| char::from_digit(*digit as u32, 10))
+            .unwrap_or('?');
+        len += character.encode_utf8(&mut buffer[len..]).len();
+    }
+    f.pad(core::str::from_utf8(&buffer[..len]).expect("buffer is UTF-8"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn nhs_number() -> NHSNumber {
+        NHSNumber::new_unchecked([0, 1, 2, 3, 4, 5, 6, 7, 8, 9])
+    }
+
+    #[test]
+    fn test_styled() {
+        assert_eq!(
+            Styled::new(nhs_number(), Style::Spaced).to_string(),
+            "012 345 6789"
+        );
+        assert_eq!(
+            Styled::new(nhs_number(), Style::Compact).to_string(),
+            "0123456789"
+        );
+        assert_eq!(
+            Styled::new(nhs_number(), Style::Hyphenated).to_string(),
+            "012-345-6789"
+        );
+    }
+
+    #[test]
+    fn test_styled_with_padding() {
+        let styled = Styled::new(nhs_number(), Style::Compact);
+        assert_eq!(format!("[{:>12}]", styled), "[  0123456789]");
+        assert_eq!(format!("[{:<12}]", styled), "[0123456789  ]");
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(Style::default(), Style::Spaced);
+    }
+}
@@ -1,58 +1,479 @@
+#[cfg(feature = "testable")]
+use crate::CheckDigit;
 use crate::NHSNumber;
-use rand::Rng;
-use std::ops::RangeInclusive;
+#[cfg(feature = "std")]
+use core::ops::RangeInclusive;
+#[cfg(feature = "testable")]
+use rand::rngs::StdRng;
+#[cfg(feature = "testable")]
+use rand::{Rng, SeedableRng};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "std")]
 use std::sync::LazyLock;
 
-/// Get the NHS Number testable range minimum value.
-/// This number is valid but is never going to be issued.
+/// Get the NHS Number testable range minimum value, which is 999 000 0000.
+///
+/// This is the lower bound of the range, but is not itself valid, because its
+/// first nine digits yield the invalid checksum 10. The first valid number in
+/// the range is 999 000 0018; see [TESTABLE_RANGE].
 ///
 /// Example:
 ///
 /// ```rust
 /// use nhs_number::NHSNumber;
 /// use nhs_number::testable::TESTABLE_MIN;
-/// let nhs_number = NHSNumber { digits: [9, 9, 9, 0, 1, 2, 3, 4, 5, 6] };
+/// let nhs_number = NHSNumber::try_new([9, 9, 9, 0, 1, 2, 3, 4, 5, 4]).unwrap();
 /// assert!(nhs_number >= *TESTABLE_MIN);
 /// ```
 ///
+#[cfg(feature = "std")]
 #[allow(dead_code)]
-pub static TESTABLE_MIN: LazyLock<NHSNumber> = LazyLock::new(|| NHSNumber {
-    digits: [9, 9, 9, 0, 0, 0, 0, 0, 0, 0],
-});
+pub static TESTABLE_MIN: LazyLock<NHSNumber> =
+    LazyLock::new(|| NHSNumber::new_unchecked([9, 9, 9, 0, 0, 0, 0, 0, 0, 0]));
 
-/// Get the NHS Number testable range maximum value.
-/// This number is valid but is never going to be issued.
+/// Get the NHS Number testable range maximum value, which is 999 999 9999.
+///
+/// This is the upper bound of the range, but is not itself valid, because it
+/// has repeated digits. The last valid number in the range is 999 999 9980;
+/// see [TESTABLE_RANGE].
 ///
 /// Example:
 ///
 /// ```rust
 /// use nhs_number::NHSNumber;
 /// use nhs_number::testable::TESTABLE_MAX;
-/// let nhs_number = NHSNumber { digits: [9, 9, 9, 0, 1, 2, 3, 4, 5, 6] };
+/// let nhs_number = NHSNumber::try_new([9, 9, 9, 0, 1, 2, 3, 4, 5, 4]).unwrap();
 /// assert!(nhs_number <= *TESTABLE_MAX);
 /// ```
 ///
+#[cfg(feature = "std")]
 #[allow(dead_code)]
-pub static TESTABLE_MAX: LazyLock<NHSNumber> = LazyLock::new(|| NHSNumber {
-    digits: [9, 9, 9, 9, 9, 9, 9, 9, 9, 9],
-});
+pub static TESTABLE_MAX: LazyLock<NHSNumber> =
+    LazyLock::new(|| NHSNumber::new_unchecked([9, 9, 9, 9, 9, 9, 9, 9, 9, 9]));
 
-/// Get the NHS Number testable range.
-/// This range is valid but is never going to be issued.
+/// Get the NHS Number testable range, from [TESTABLE_MIN] to [TESTABLE_MAX].
+/// This range is never going to be issued.
 ///
 /// Example:
 /// ```rust
 ///  use nhs_number::{NHSNumber, testable::*};
-/// let nhs_number = NHSNumber { digits: [9, 9, 9, 0, 1, 2, 3, 4, 5, 6] };
+/// let nhs_number = NHSNumber::try_new([9, 9, 9, 0, 1, 2, 3, 4, 5, 4]).unwrap();
 ///  assert!(TESTABLE_RANGE_INCLUSIVE.contains(&nhs_number));
 /// ```
+#[cfg(feature = "std")]
 #[allow(dead_code)]
 pub static TESTABLE_RANGE_INCLUSIVE: LazyLock<RangeInclusive<NHSNumber>> =
     LazyLock::new(|| RangeInclusive::new(*TESTABLE_MIN, *TESTABLE_MAX));
 
+/// The first body i.e. first nine digits, of the testable range.
+const TESTABLE_BODY_MIN: u32 = 999_000_000;
+
+/// The last body i.e. first nine digits, of the testable range that has a
+/// valid number, because 999 999 9999 has repeated digits so is not valid.
+const TESTABLE_BODY_MAX: u32 = 999_999_998;
+
+/// The count of valid numbers in the testable range.
+const TESTABLE_LEN: usize = 909_090;
+
+/// The count of digits of a testable body after the prefix 999.
+const TESTABLE_SUFFIX_DIGITS: usize = 6;
+
+/// The checksum of the testable body prefix 999, modulo 11.
+const TESTABLE_PREFIX_RESIDUE: usize = (9 * 10 + 9 * 9 + 9 * 8) % 11;
+
+/// The count of ways to choose the last `k` digits of a body, by the weighted
+/// sum of those digits modulo 11, for `k` from 0 to 6.
+const TESTABLE_SUFFIX_COUNTS: [[u32; 11]; TESTABLE_SUFFIX_DIGITS + 1] = {
+    let mut counts = [[0; 11]; TESTABLE_SUFFIX_DIGITS + 1];
+    counts[0][0] = 1;
+    let mut k = 1;
+    while k <= TESTABLE_SUFFIX_DIGITS {
+        // The weight of the digit at this position from the right.
+        let weight = k + 1;
+        let mut residue = 0;
+        while residue < 11 {
+            let mut digit = 0;
+            while digit < 10 {
+                counts[k][(residue + digit * weight) % 11] += counts[k - 1][residue];
+                digit += 1;
+            }
+            residue += 1;
+        }
+        k += 1;
+    }
+    counts
+};
+
+/// The count of ways to choose the last `k` digits of a body, given the
+/// weighted sum of the digits before them modulo 11, such that the body does
+/// not yield the invalid checksum 10, i.e. the whole sum modulo 11 is not 1.
+fn testable_completions(k: usize, residue: usize) -> usize {
+    let total = 10usize.pow(k as u32);
+    total - TESTABLE_SUFFIX_COUNTS[k][(12 - residue) % 11] as usize
+}
+
+/// The count of valid numbers in the testable range whose body is less than
+/// this body, which is from [TESTABLE_BODY_MIN] to [TESTABLE_BODY_MAX] + 1.
+///
+/// This counts digit by digit, so it does not iterate the range.
+fn testable_rank(body: u32) -> usize {
+    let suffix = body - TESTABLE_BODY_MIN;
+    let mut rank = 0;
+    let mut residue = TESTABLE_PREFIX_RESIDUE;
+    for position in 0..TESTABLE_SUFFIX_DIGITS {
+        let rest = TESTABLE_SUFFIX_DIGITS - 1 - position;
+        let weight = rest + 2;
+        let digit = (suffix / 10u32.pow(rest as u32) % 10) as usize;
+        for smaller in 0..digit {
+            rank += testable_completions(rest, (residue + smaller * weight) % 11);
+        }
+        residue = (residue + digit * weight) % 11;
+    }
+    rank
+}
+
+/// The body of the nth valid number in the testable range, counting from
+/// zero, where `n` is less than [TESTABLE_LEN].
+///
+/// This chooses digit by digit, so it does not iterate the range.
+fn testable_body(n: usize) -> u32 {
+    debug_assert!(n < TESTABLE_LEN);
+    let mut remaining = n;
+    let mut suffix = 0;
+    let mut residue = TESTABLE_PREFIX_RESIDUE;
+    for position in 0..TESTABLE_SUFFIX_DIGITS {
+        let rest = TESTABLE_SUFFIX_DIGITS - 1 - position;
+        let weight = rest + 2;
+        let mut digit = 0;
+        loop {
+            let count = testable_completions(rest, (residue + digit * weight) % 11);
+            if remaining < count {
+                break;
+            }
+            remaining -= count;
+            digit += 1;
+        }
+        suffix = suffix * 10 + digit as u32;
+        residue = (residue + digit * weight) % 11;
+    }
+    TESTABLE_BODY_MIN + suffix
+}
+
+/// NHS Number testable range, which can iterate every valid number in the
+/// range 999 000 0000 to 999 999 9999, in order.
+///
+/// A valid number has a valid check digit, so the iteration skips every body
+/// i.e. first nine digits, that yields the invalid checksum 10. The iteration
+/// also skips 999 999 9999, which has repeated digits.
+///
+/// Example:
+///
+/// ```rust
+/// use nhs_number::testable::TESTABLE_RANGE;
+/// let mut iter = TESTABLE_RANGE.iter();
+/// assert_eq!(iter.next().unwrap().to_string(), "999 000 0018");
+/// assert_eq!(iter.next().unwrap().to_string(), "999 000 0026");
+/// ```
+///
+/// Example of sharding the range across workers:
+///
+/// ```rust
+/// use nhs_number::{NHSNumber, testable::TESTABLE_RANGE};
+/// let workers = 4;
+/// let worker = 1;
+/// let shard: Vec<NHSNumber> = TESTABLE_RANGE.iter().skip(worker).step_by(workers).take(2).collect();
+/// assert_eq!(shard[0], TESTABLE_RANGE.nth(1).unwrap());
+/// assert_eq!(shard[1], TESTABLE_RANGE.nth(5).unwrap());
+/// ```
+///
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct TestableRange;
+
+/// The NHS Number testable range, which can iterate every valid number.
+pub const TESTABLE_RANGE: TestableRange = TestableRange;
+
+impl TestableRange {
+    /// Iterate every valid number in the testable range, in order.
+    pub fn iter(&self) -> TestableRangeIter {
+        TestableRangeIter {
+            front: TESTABLE_BODY_MIN,
+            back: TESTABLE_BODY_MAX + 1,
+            len: TESTABLE_LEN,
+        }
+    }
+
+    /// Get the count of valid numbers in the testable range.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use nhs_number::testable::TESTABLE_RANGE;
+    /// assert_eq!(TESTABLE_RANGE.len(), 909_090);
+    /// ```
+    ///
+    pub fn len(&self) -> usize {
+        TESTABLE_LEN
+    }
+
+    /// Is the testable range empty? This is always false.
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Get the nth valid number in the testable range, counting from zero.
+    ///
+    /// This computes the number from its index, without iterating the range,
+    /// so it is cheap for any index.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use nhs_number::testable::TESTABLE_RANGE;
+    /// assert_eq!(TESTABLE_RANGE.nth(0).unwrap().to_string(), "999 000 0018");
+    /// assert_eq!(TESTABLE_RANGE.nth(909_089).unwrap().to_string(), "999 999 9980");
+    /// assert_eq!(TESTABLE_RANGE.nth(909_090), None);
+    /// ```
+    ///
+    pub fn nth(&self, n: usize) -> Option<NHSNumber> {
+        self.iter().nth(n)
+    }
+
+    /// Does the testable range contain this valid number?
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use nhs_number::{NHSNumber, testable::TESTABLE_RANGE};
+    /// let nhs_number = NHSNumber::try_new([9, 9, 9, 3, 4, 5, 6, 7, 8, 0]).unwrap();
+    /// assert!(TESTABLE_RANGE.contains(&nhs_number));
+    /// let nhs_number = NHSNumber::new_unchecked([9, 9, 9, 3, 4, 5, 6, 7, 8, 1]);
+    /// assert!(!TESTABLE_RANGE.contains(&nhs_number));
+    /// ```
+    ///
+    pub fn contains(&self, nhs_number: &NHSNumber) -> bool {
+        (TESTABLE_BODY_MIN..=TESTABLE_BODY_MAX).contains(&nhs_number.body())
+            && nhs_number.validate_check_digit()
+    }
+}
+
+impl IntoIterator for TestableRange {
+    type Item = NHSNumber;
+    type IntoIter = TestableRangeIter;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator of every valid number in the testable range, in order.
+///
+/// This iterator knows its exact length, and can iterate from either end. The
+/// method `nth`, and so `skip` and `step_by`, jumps without iterating.
+///
+#[derive(Debug, Clone)]
+pub struct TestableRangeIter {
+    /// The next body to try from the front.
+    front: u32,
+
+    /// The body after the last body to try from the back.
+    back: u32,
+
+    /// The count of valid numbers that remain.
+    len: usize,
+}
+
+impl Iterator for TestableRangeIter {
+    type Item = NHSNumber;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.front < self.back {
+            let body = self.front;
+            self.front += 1;
+            if let Some(nhs_number) = NHSNumber::from_body_value(body) {
+                self.len -= 1;
+                return Some(nhs_number);
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.len {
+            self.front = self.back;
+            self.len = 0;
+            return None;
+        }
+        let body = testable_body(testable_rank(self.front) + n);
+        self.front = body + 1;
+        self.len -= n + 1;
+        NHSNumber::from_body_value(body)
+    }
+}
+
+impl DoubleEndedIterator for TestableRangeIter {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        while self.front < self.back {
+            self.back -= 1;
+            if let Some(nhs_number) = NHSNumber::from_body_value(self.back) {
+                self.len -= 1;
+                return Some(nhs_number);
+            }
+        }
+        None
+    }
+}
+
+impl ExactSizeIterator for TestableRangeIter {}
+
+impl core::iter::FusedIterator for TestableRangeIter {}
+
+/// The count of indexes that the testable allocator permutes, which is one
+/// index per body from 999 000 000 to 999 999 998.
+const TESTABLE_ALLOCATOR_DOMAIN: u32 = TESTABLE_BODY_MAX - TESTABLE_BODY_MIN + 1;
+
+/// The count of bits in each half of the testable allocator Feistel network,
+/// which is enough to cover the domain.
+const TESTABLE_ALLOCATOR_HALF_BITS: u32 = 10;
+
+/// The count of rounds of the testable allocator Feistel network.
+const TESTABLE_ALLOCATOR_ROUNDS: u64 = 8;
+
+/// NHS Number testable allocator, which hands out distinct valid numbers in
+/// the testable range, in a pseudo-random order that depends on a key.
+///
+/// The allocator never returns the same number twice for the same key: it
+/// walks a keyed permutation of the testable range, which is a format-
+/// preserving Feistel network with cycle walking. After the allocator hands
+/// out every valid number in the testable range, it returns `None`.
+///
+/// The allocator can save its state, then resume later, such as when a
+/// process populates a database in batches.
+///
+/// Example:
+///
+/// ```rust
+/// use nhs_number::testable::TestableAllocator;
+/// use std::collections::HashSet;
+/// let mut allocator = TestableAllocator::new(42);
+/// let numbers: HashSet<_> = (0..1000).map(|_| allocator.allocate().unwrap()).collect();
+/// assert_eq!(numbers.len(), 1000);
+/// ```
+///
+/// Example of saving then resuming:
+///
+/// ```rust
+/// use nhs_number::testable::TestableAllocator;
+/// let mut allocator = TestableAllocator::new(42);
+/// allocator.allocate();
+/// let state = allocator.state();
+/// let expect = allocator.allocate();
+/// let mut allocator = TestableAllocator::resume(state);
+/// assert_eq!(allocator.allocate(), expect);
+/// ```
+///
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct TestableAllocator {
+    key: u64,
+    position: u32,
+}
+
+/// NHS Number testable allocator state, which can resume an allocator.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TestableAllocatorState {
+    /// The key of the permutation.
+    pub key: u64,
+
+    /// The position in the permutation of the next index to try.
+    pub position: u32,
+}
+
+impl TestableAllocator {
+    /// Create a new testable allocator with the provided key.
+    pub fn new(key: u64) -> Self {
+        TestableAllocator { key, position: 0 }
+    }
+
+    /// Resume a testable allocator from a saved state.
+    pub fn resume(state: TestableAllocatorState) -> Self {
+        TestableAllocator {
+            key: state.key,
+            position: state.position,
+        }
+    }
+
+    /// Get the state of this testable allocator, which can resume it later.
+    pub fn state(&self) -> TestableAllocatorState {
+        TestableAllocatorState {
+            key: self.key,
+            position: self.position,
+        }
+    }
+
+    /// Allocate the next distinct valid number in the testable range, or
+    /// return `None` if every valid number is already allocated.
+    pub fn allocate(&mut self) -> Option<NHSNumber> {
+        while self.position < TESTABLE_ALLOCATOR_DOMAIN {
+            let index = self.permute(self.position);
+            self.position += 1;
+            if let Some(nhs_number) = NHSNumber::from_body_value(TESTABLE_BODY_MIN + index) {
+                return Some(nhs_number);
+            }
+        }
+        None
+    }
+
+    /// Permute an index in the domain, by using the Feistel network, and by
+    /// cycle walking until the result is in the domain.
+    fn permute(&self, index: u32) -> u32 {
+        let mut index = index;
+        loop {
+            index = self.feistel(index);
+            if index < TESTABLE_ALLOCATOR_DOMAIN {
+                return index;
+            }
+        }
+    }
+
+    /// Encrypt an index by using a balanced Feistel network, which is a
+    /// permutation of the integers that fit in twice the half bits.
+    fn feistel(&self, index: u32) -> u32 {
+        let mask = (1 << TESTABLE_ALLOCATOR_HALF_BITS) - 1;
+        let mut left = index >> TESTABLE_ALLOCATOR_HALF_BITS;
+        let mut right = index & mask;
+        for round in 0..TESTABLE_ALLOCATOR_ROUNDS {
+            let f = splitmix64(self.key ^ (round << 32) ^ right as u64) as u32 & mask;
+            (left, right) = (right, left ^ f);
+        }
+        (left << TESTABLE_ALLOCATOR_HALF_BITS) | right
+    }
+}
+
+impl Iterator for TestableAllocator {
+    type Item = NHSNumber;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.allocate()
+    }
+}
+
+/// Mix the bits of a 64-bit integer, by using the SplitMix64 finalizer.
+fn splitmix64(x: u64) -> u64 {
+    let mut z = x.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
 /// Generate a NHS Number testable range random sample.
 /// The generated number is valid but is never going to be issued.
 ///
+/// The generated number always has a valid check digit, so it passes the
+/// method [NHSNumber::validate].
+///
 /// Example:
 ///
 /// ```rust
@@ -60,35 +481,360 @@ pub static TESTABLE_RANGE_INCLUSIVE: LazyLock<RangeInclusive<NHSNumber>> =
 /// let nhs_number = testable_random_sample();
 /// assert!(nhs_number >= *TESTABLE_MIN);
 /// assert!(nhs_number <= *TESTABLE_MAX);
+/// assert!(nhs_number.validate_check_digit());
 /// ```
 ///
+#[cfg(all(feature = "testable", feature = "std"))]
 #[allow(dead_code)]
 pub fn testable_random_sample() -> NHSNumber {
-    let mut rng = rand::rng();
-    NHSNumber {
-        digits: [
-            9,
-            9,
-            9,
-            rng.random_range(0..=9) as i8,
-            rng.random_range(0..=9) as i8,
-            rng.random_range(0..=9) as i8,
-            rng.random_range(0..=9) as i8,
-            rng.random_range(0..=9) as i8,
-            rng.random_range(0..=9) as i8,
-            rng.random_range(0..=9) as i8,
-        ],
+    testable_random_sample_with_rng(&mut rand::rng())
+}
+
+/// Generate a NHS Number testable range random sample by using the provided
+/// random number generator, such as a seeded generator for reproducibility.
+/// The generated number is valid but is never going to be issued.
+///
+/// Example:
+///
+/// ```rust
+/// use nhs_number::testable::*;
+/// use rand::{SeedableRng, rngs::StdRng};
+/// let a = testable_random_sample_with_rng(&mut StdRng::seed_from_u64(42));
+/// let b = testable_random_sample_with_rng(&mut StdRng::seed_from_u64(42));
+/// assert_eq!(a, b);
+/// ```
+///
+#[cfg(feature = "testable")]
+#[allow(dead_code)]
+pub fn testable_random_sample_with_rng<R: Rng + ?Sized>(rng: &mut R) -> NHSNumber {
+    loop {
+        let mut digits = testable_random_digits(rng);
+        if let CheckDigit::Digit(check_digit) = crate::calculate_check_digit(digits) {
+            digits[9] = check_digit;
+            if crate::validate(digits).is_ok() {
+                return NHSNumber { digits };
+            }
+        }
     }
 }
 
+/// Generate a NHS Number testable range random sample with an invalid check
+/// digit, which is for negative testing.
+/// The generated number is never going to be issued.
+///
+/// Example:
+///
+/// ```rust
+/// use nhs_number::{NHSNumber, testable::*};
+/// let nhs_number = testable_random_invalid_sample();
+/// assert!(nhs_number >= *TESTABLE_MIN);
+/// assert!(nhs_number <= *TESTABLE_MAX);
+/// assert!(!nhs_number.validate_check_digit());
+/// ```
+///
+#[cfg(all(feature = "testable", feature = "std"))]
+#[allow(dead_code)]
+pub fn testable_random_invalid_sample() -> NHSNumber {
+    testable_random_invalid_sample_with_rng(&mut rand::rng())
+}
+
+/// Generate a NHS Number testable range random sample with an invalid check
+/// digit by using the provided random number generator.
+/// The generated number is never going to be issued.
+///
+/// Example:
+///
+/// ```rust
+/// use nhs_number::testable::*;
+/// use rand::{SeedableRng, rngs::StdRng};
+/// let a = testable_random_invalid_sample_with_rng(&mut StdRng::seed_from_u64(42));
+/// let b = testable_random_invalid_sample_with_rng(&mut StdRng::seed_from_u64(42));
+/// assert_eq!(a, b);
+/// assert!(!a.validate_check_digit());
+/// ```
+///
+#[cfg(feature = "testable")]
+#[allow(dead_code)]
+pub fn testable_random_invalid_sample_with_rng<R: Rng + ?Sized>(rng: &mut R) -> NHSNumber {
+    let mut digits = testable_random_digits(rng);
+    digits[9] = match crate::calculate_check_digit(digits) {
+        CheckDigit::Digit(check_digit) => (check_digit + rng.random_range(1..=9)) % 10,
+        CheckDigit::Invalid => rng.random_range(0..=9),
+    };
+    NHSNumber { digits }
+}
+
+/// Generate an endless reproducible stream of NHS Number testable range
+/// random samples from a seed.
+/// The generated numbers are valid but are never going to be issued.
+///
+/// The same seed yields the same stream, for the same version of the `rand`
+/// crate.
+///
+/// Example:
+///
+/// ```rust
+/// use nhs_number::{NHSNumber, testable::*};
+/// let a: Vec<NHSNumber> = testable_random_samples(42).take(3).collect();
+/// let b: Vec<NHSNumber> = testable_random_samples(42).take(3).collect();
+/// assert_eq!(a, b);
+/// ```
+///
+#[cfg(feature = "testable")]
+#[allow(dead_code)]
+pub fn testable_random_samples(seed: u64) -> TestableSamples<StdRng> {
+    TestableSamples::new(StdRng::seed_from_u64(seed))
+}
+
+/// Iterator of NHS Number testable range random samples, which uses any
+/// random number generator.
+/// The generated numbers are valid but are never going to be issued.
+///
+/// Example:
+///
+/// ```rust
+/// use nhs_number::testable::*;
+/// use rand::{SeedableRng, rngs::SmallRng};
+/// let samples = TestableSamples::new(SmallRng::seed_from_u64(42));
+/// for nhs_number in samples.take(3) {
+///     assert!(nhs_number.validate_check_digit());
+/// }
+/// ```
+///
+#[cfg(feature = "testable")]
+#[derive(Debug, Clone)]
+pub struct TestableSamples<R: Rng> {
+    rng: R,
+}
+
+#[cfg(feature = "testable")]
+impl<R: Rng> TestableSamples<R> {
+    /// Create a new iterator of testable samples that uses the provided
+    /// random number generator.
+    pub fn new(rng: R) -> Self {
+        TestableSamples { rng }
+    }
+}
+
+#[cfg(feature = "testable")]
+impl<R: Rng> Iterator for TestableSamples<R> {
+    type Item = NHSNumber;
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(testable_random_sample_with_rng(&mut self.rng))
+    }
+}
+
+/// Generate random digits in the testable range, with a placeholder check
+/// digit of 0.
+#[cfg(feature = "testable")]
+fn testable_random_digits<R: Rng + ?Sized>(rng: &mut R) -> [i8; 10] {
+    [
+        9,
+        9,
+        9,
+        rng.random_range(0..=9),
+        rng.random_range(0..=9),
+        rng.random_range(0..=9),
+        rng.random_range(0..=9),
+        rng.random_range(0..=9),
+        rng.random_range(0..=9),
+        0,
+    ]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[cfg(all(feature = "testable", feature = "std"))]
     #[test]
     fn test_random() {
         let a = testable_random_sample();
         assert!(a >= *TESTABLE_MIN);
         assert!(a <= *TESTABLE_MAX);
     }
+
+    #[cfg(all(feature = "testable", feature = "std"))]
+    #[test]
+    fn test_random_is_valid() {
+        for _ in 0..1000 {
+            let a = testable_random_sample();
+            assert!(a.validate_check_digit());
+            assert_eq!(a.validate(), Ok(()));
+        }
+    }
+
+    #[cfg(all(feature = "testable", feature = "std"))]
+    #[test]
+    fn test_random_invalid() {
+        for _ in 0..1000 {
+            let a = testable_random_invalid_sample();
+            assert!(a >= *TESTABLE_MIN);
+            assert!(a <= *TESTABLE_MAX);
+            assert!(!a.validate_check_digit());
+        }
+    }
+
+    #[cfg(feature = "testable")]
+    #[test]
+    fn test_random_with_rng() {
+        let a = testable_random_sample_with_rng(&mut StdRng::seed_from_u64(1));
+        let b = testable_random_sample_with_rng(&mut StdRng::seed_from_u64(1));
+        let c = testable_random_sample_with_rng(&mut StdRng::seed_from_u64(2));
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert!(a.validate_check_digit());
+    }
+
+    #[cfg(feature = "testable")]
+    #[test]
+    fn test_random_invalid_with_rng() {
+        let a = testable_random_invalid_sample_with_rng(&mut StdRng::seed_from_u64(1));
+        let b = testable_random_invalid_sample_with_rng(&mut StdRng::seed_from_u64(1));
+        assert_eq!(a, b);
+        assert!(!a.validate_check_digit());
+    }
+
+    #[cfg(feature = "testable")]
+    #[test]
+    fn test_random_samples() {
+        let a: Vec<NHSNumber> = testable_random_samples(1).take(100).collect();
+        let b: Vec<NHSNumber> = testable_random_samples(1).take(100).collect();
+        let c: Vec<NHSNumber> = testable_random_samples(2).take(100).collect();
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert!(a.iter().all(|nhs_number| nhs_number.validate().is_ok()));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_testable_min_and_max() {
+        assert_eq!(
+            TESTABLE_MIN.calculate_check_digit(),
+            crate::CheckDigit::Invalid
+        );
+        assert!(TESTABLE_MAX.validate().is_err());
+        assert!(*TESTABLE_MIN < TESTABLE_RANGE.iter().next().unwrap());
+        assert!(*TESTABLE_MAX > TESTABLE_RANGE.iter().next_back().unwrap());
+    }
+
+    #[test]
+    fn test_testable_range_iter() {
+        let mut count = 0;
+        let mut previous: Option<NHSNumber> = None;
+        for nhs_number in TESTABLE_RANGE.iter() {
+            assert!(nhs_number.validate().is_ok());
+            if let Some(previous) = previous {
+                assert!(previous < nhs_number);
+                assert_eq!(previous.successor(), Some(nhs_number));
+            }
+            previous = Some(nhs_number);
+            count += 1;
+        }
+        assert_eq!(count, TESTABLE_RANGE.len());
+    }
+
+    #[test]
+    fn test_testable_range_iter_len() {
+        let mut iter = TESTABLE_RANGE.iter();
+        assert_eq!(iter.len(), 909_090);
+        iter.next();
+        iter.next_back();
+        assert_eq!(iter.len(), 909_088);
+    }
+
+    #[test]
+    fn test_testable_range_iter_rev() {
+        let mut iter = TESTABLE_RANGE.iter().rev();
+        assert_eq!(
+            iter.next(),
+            Some(NHSNumber::new_unchecked([9, 9, 9, 9, 9, 9, 9, 9, 8, 0]))
+        );
+        assert_eq!(
+            iter.next(),
+            Some(NHSNumber::new_unchecked([9, 9, 9, 9, 9, 9, 9, 9, 7, 2]))
+        );
+    }
+
+    #[test]
+    fn test_testable_range_nth() {
+        assert_eq!(
+            TESTABLE_RANGE.nth(0),
+            Some(NHSNumber::new_unchecked([9, 9, 9, 0, 0, 0, 0, 0, 1, 8]))
+        );
+        assert_eq!(TESTABLE_RANGE.nth(TESTABLE_RANGE.len()), None);
+    }
+
+    #[test]
+    fn test_testable_range_nth_matches_iter() {
+        for (n, nhs_number) in TESTABLE_RANGE.iter().enumerate() {
+            if n % 997 == 0 || n + 1 == TESTABLE_RANGE.len() {
+                assert_eq!(TESTABLE_RANGE.nth(n), Some(nhs_number));
+            }
+        }
+        assert_eq!(testable_rank(TESTABLE_BODY_MIN), 0);
+        assert_eq!(testable_rank(TESTABLE_BODY_MAX + 1), TESTABLE_LEN);
+    }
+
+    #[test]
+    fn test_testable_range_iter_nth() {
+        let mut iter = TESTABLE_RANGE.iter();
+        iter.next();
+        assert_eq!(iter.nth(4), TESTABLE_RANGE.nth(5));
+        assert_eq!(iter.next(), TESTABLE_RANGE.nth(6));
+        assert_eq!(iter.len(), TESTABLE_RANGE.len() - 7);
+        let shard: Vec<NHSNumber> = TESTABLE_RANGE.iter().skip(3).step_by(1000).collect();
+        assert_eq!(shard.len(), 910);
+        assert_eq!(shard[909], TESTABLE_RANGE.nth(909_003).unwrap());
+        let mut iter = TESTABLE_RANGE.iter();
+        assert_eq!(iter.nth(TESTABLE_RANGE.len()), None);
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_testable_range_contains() {
+        assert!(TESTABLE_RANGE.contains(&NHSNumber::new_unchecked([9, 9, 9, 0, 0, 0, 0, 0, 1, 8])));
+        assert!(
+            !TESTABLE_RANGE.contains(&NHSNumber::new_unchecked([9, 9, 9, 0, 0, 0, 0, 0, 1, 9]))
+        );
+        assert!(
+            !TESTABLE_RANGE.contains(&NHSNumber::new_unchecked([9, 9, 9, 9, 9, 9, 9, 9, 9, 9]))
+        );
+        assert!(
+            !TESTABLE_RANGE.contains(&NHSNumber::new_unchecked([9, 4, 3, 4, 7, 6, 5, 9, 1, 9]))
+        );
+    }
+
+    #[test]
+    fn test_testable_allocator_is_unique_and_complete() {
+        let mut allocator = TestableAllocator::new(42);
+        let mut seen = std::collections::HashSet::new();
+        while let Some(nhs_number) = allocator.allocate() {
+            assert!(TESTABLE_RANGE.contains(&nhs_number));
+            assert!(seen.insert(nhs_number));
+        }
+        assert_eq!(seen.len(), TESTABLE_RANGE.len());
+        assert_eq!(allocator.allocate(), None);
+    }
+
+    #[test]
+    fn test_testable_allocator_key() {
+        let a: Vec<NHSNumber> = TestableAllocator::new(1).take(100).collect();
+        let b: Vec<NHSNumber> = TestableAllocator::new(1).take(100).collect();
+        let c: Vec<NHSNumber> = TestableAllocator::new(2).take(100).collect();
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn test_testable_allocator_resume() {
+        let mut allocator = TestableAllocator::new(42);
+        let a: Vec<NHSNumber> = allocator.by_ref().take(50).collect();
+        let state = allocator.state();
+        let b: Vec<NHSNumber> = allocator.take(50).collect();
+        let c: Vec<NHSNumber> = TestableAllocator::resume(state).take(50).collect();
+        assert_eq!(b, c);
+        assert!(a.iter().all(|nhs_number| !b.contains(nhs_number)));
+    }
 }
@@ -0,0 +1,121 @@
+use crate::{CheckDigit, IssuingRange};
+use core::fmt;
+
+/// NHS Number Validation Error, which is for the validated constructors such
+/// as [NHSNumber::try_new](crate::NHSNumber::try_new), and for the full
+/// validation [NHSNumber::validate](crate::NHSNumber::validate).
+///
+/// Example:
+///
+/// ```rust
+/// use nhs_number::{NHSNumber, validation_error::ValidationError};
+/// let result = NHSNumber::try_new([9, 9, 9, 3, 4, 5, 6, 7, 8, 42]);
+/// assert_eq!(result, Err(ValidationError::InvalidDigit { index: 9, digit: 42 }));
+/// ```
+///
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ValidationError {
+    /// The digit at this index is not in the range 0–9.
+    InvalidDigit { index: usize, digit: i16 },
+
+    /// The check digit differs from the calculated check digit.
+    InvalidCheckDigit { expected: CheckDigit, actual: i8 },
+
+    /// The digits are all the same digit, which is never issued.
+    RepeatedDigits { digit: i8 },
+
+    /// The number is neither in a currently issued range nor in the test range.
+    OutOfRange { range: IssuingRange },
+
+    /// The integer has too many digits, which is this count of digits.
+    ///
+    /// The error omits the integer, so that it does not leak a NHS Number.
+    TooLarge { digits: usize },
+
+    /// The body i.e. the first nine digits, yields the invalid checksum 10, so
+    /// can never have a valid check digit.
+    ///
+    /// The error omits the body, so that it does not leak a NHS Number.
+    InvalidBody,
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ValidationError::InvalidDigit { index, digit } => {
+                write!(f, "invalid digit {} at index {}", digit, index)
+            }
+            ValidationError::InvalidCheckDigit { expected, actual } => match expected {
+                CheckDigit::Digit(expected) => write!(
+                    f,
+                    "invalid check digit: expected {}, found {}",
+                    expected, actual
+                ),
+                CheckDigit::Invalid => write!(
+                    f,
+                    "invalid check digit: the first nine digits can never have a valid check digit, found {}",
+                    actual
+                ),
+            },
+            ValidationError::RepeatedDigits { digit } => {
+                write!(f, "repeated digits: every digit is {}", digit)
+            }
+            ValidationError::OutOfRange { range } => {
+                write!(f, "out of range: {} numbers are not issuable", range)
+            }
+            ValidationError::TooLarge { digits } => {
+                write!(f, "too large: the integer has {} digits", digits)
+            }
+            ValidationError::InvalidBody => write!(
+                f,
+                "invalid body: the first nine digits can never have a valid check digit"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for ValidationError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            ValidationError::InvalidDigit {
+                index: 1,
+                digit: 42
+            }
+            .to_string(),
+            "invalid digit 42 at index 1"
+        );
+        assert_eq!(
+            ValidationError::InvalidCheckDigit {
+                expected: CheckDigit::Invalid,
+                actual: 0
+            }
+            .to_string(),
+            "invalid check digit: the first nine digits can never have a valid check digit, found 0"
+        );
+        assert_eq!(
+            ValidationError::RepeatedDigits { digit: 4 }.to_string(),
+            "repeated digits: every digit is 4"
+        );
+        assert_eq!(
+            ValidationError::OutOfRange {
+                range: IssuingRange::ScottishChi
+            }
+            .to_string(),
+            "out of range: Scottish CHI numbers are not issuable"
+        );
+        assert_eq!(
+            ValidationError::TooLarge { digits: 11 }.to_string(),
+            "too large: the integer has 11 digits"
+        );
+        assert_eq!(
+            ValidationError::InvalidBody.to_string(),
+            "invalid body: the first nine digits can never have a valid check digit"
+        );
+    }
+}